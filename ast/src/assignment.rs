@@ -38,7 +38,7 @@ ast_struct! {
     }
 }
 
-ast_node! {
+ast_enum! {
     pub enum AssignmentProp {
         Single(SingleNameAssignmentProp),
         Named(NamedAssignmentProp)
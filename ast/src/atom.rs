@@ -0,0 +1,166 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Immutable string used for names, cloning only increments a reference count.
+///
+/// Identical names may share the same allocation when parsing with interning enabled, see
+/// `Atom::ptr_eq`. Atoms compare equal by content regardless.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Atom(Arc<str>);
+
+impl Atom {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if both atoms share the same allocation, i.e. they were interned together.
+    pub fn ptr_eq(&self, other: &Atom) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Default for Atom {
+    fn default() -> Self {
+        Atom::from("")
+    }
+}
+
+impl Deref for Atom {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Atom {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Atom {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Atom {
+    fn from(value: &str) -> Self {
+        Atom(Arc::from(value))
+    }
+}
+
+impl From<String> for Atom {
+    fn from(value: String) -> Self {
+        Atom(Arc::from(value))
+    }
+}
+
+impl From<&String> for Atom {
+    fn from(value: &String) -> Self {
+        Atom::from(value.as_str())
+    }
+}
+
+impl From<Atom> for String {
+    fn from(value: Atom) -> Self {
+        value.0.to_string()
+    }
+}
+
+impl PartialEq<str> for Atom {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Atom {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Atom {
+    fn eq(&self, other: &String) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<Atom> for str {
+    fn eq(&self, other: &Atom) -> bool {
+        self == &*other.0
+    }
+}
+
+impl PartialEq<Atom> for &str {
+    fn eq(&self, other: &Atom) -> bool {
+        *self == &*other.0
+    }
+}
+
+impl Debug for Atom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&*self.0, f)
+    }
+}
+
+impl Display for Atom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Serialize for Atom {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Atom {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Atom::from)
+    }
+}
+
+/// Deduplicates atoms, interning the same string twice returns atoms sharing one allocation.
+#[derive(Debug, Default)]
+pub struct Interner {
+    atoms: HashSet<Atom>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the atom for `value`, only allocating the first time `value` is interned.
+    pub fn intern(&mut self, value: &str) -> Atom {
+        if let Some(atom) = self.atoms.get(value) {
+            return atom.clone();
+        }
+
+        let atom = Atom::from(value);
+        self.atoms.insert(atom.clone());
+        atom
+    }
+
+    /// Number of unique atoms.
+    pub fn len(&self) -> usize {
+        self.atoms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.atoms.is_empty()
+    }
+}
@@ -25,7 +25,7 @@ ast_struct! {
     }
 }
 
-ast_node! {
+ast_enum! {
     pub enum ObjectBindingProp {
         Single(SingleNameBinding),
         Named(NamedBinding),
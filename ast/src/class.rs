@@ -3,6 +3,8 @@ use crate::{Expr, Ident, MethodDefinition, Span};
 ast_struct! {
     pub struct DeclClass {
         pub span: Span,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub decorators: Vec<Decorator>,
         pub identifier: Ident,
         pub super_class: Option<Box<Expr>>,
         pub body: Vec<ClassElement>,
@@ -12,12 +14,22 @@ ast_struct! {
 ast_struct! {
     pub struct ExprClass {
         pub span: Span,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub decorators: Vec<Decorator>,
         pub identifier: Option<Ident>,
         pub super_class: Option<Box<Expr>>,
         pub body: Vec<ClassElement>,
     }
 }
 
+ast_struct! {
+    /// Decorator from the decorators proposal, e.g. `@a.b` or `@a(b)`.
+    pub struct Decorator {
+        pub span: Span,
+        pub expr: Expr,
+    }
+}
+
 ast_mapping! {
     pub enum ClassElement {
         Method(MethodDefinition),
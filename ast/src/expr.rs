@@ -1,6 +1,6 @@
 use crate::class::ExprClass;
 use crate::{literal::*, PatternOrExpr};
-use crate::{Body, FormalParameters, Ident, Span};
+use crate::{Body, FormalParameters, Ident, Span, StmtBlock};
 use fajt_macros::FromString;
 
 ast_mapping! {
@@ -12,6 +12,7 @@ ast_mapping! {
         Call(ExprCall),
         Class(ExprClass),
         Conditional(ExprConditional),
+        Do(ExprDo),
         Function(ExprFunction),
         IdentRef(Ident),
         Literal(ExprLiteral),
@@ -34,15 +35,31 @@ ast_mapping! {
 #[test]
 fn size_of_expr() {
     // To avoid unexpected increase in node size.
-    assert_eq!(std::mem::size_of::<Expr>(), 176);
+    assert_eq!(std::mem::size_of::<Expr>(), 168);
 }
 
 impl Expr {
-    pub fn is_nested_new(&self) -> bool {
-        if let Expr::New(expr) = &self {
-            matches!(*expr.callee, Expr::New(_))
+    /// Returns `true` if this is a `new` expression without arguments, e.g. `new a` but not
+    /// `new a()`. Those are `NewExpression` and not `MemberExpression` in the grammar.
+    pub fn is_new_without_arguments(&self) -> bool {
+        matches!(self, Expr::New(expr) if expr.arguments_span.is_none())
+    }
+
+    /// Returns the identifier reference, or `None` if this is another kind of expression.
+    pub fn try_as_ident_ref(self) -> Option<Ident> {
+        if let Expr::IdentRef(ident) = self {
+            Some(ident)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the literal expression, or `None` if this is another kind of expression.
+    pub fn try_as_literal(self) -> Option<ExprLiteral> {
+        if let Expr::Literal(literal) = self {
+            Some(literal)
         } else {
-            false
+            None
         }
     }
 
@@ -150,6 +167,33 @@ ast_node! {
     }
 }
 
+impl BinaryOperator {
+    /// Binding power and associativity of the operator, a higher binding power binds tighter.
+    pub fn binding_power(&self) -> (u8, Associativity) {
+        use Associativity::*;
+        use BinaryOperator::*;
+        match self {
+            BitwiseOR => (4, Left),
+            BitwiseXOR => (5, Left),
+            BitwiseAnd => (6, Left),
+            Equal | NotEqual | StrictEqual | StrictNotEqual => (7, Left),
+            LessThan | MoreThan | LessThanEquals | MoreThanEquals | InstanceOf | In => (8, Left),
+            ShiftLeft | ShiftRight | ShiftRightUnsigned => (9, Left),
+            Plus | Minus => (10, Left),
+            Multiplication | Division | Modulus => (11, Left),
+            Exponent => (12, Right),
+        }
+    }
+}
+
+/// Associativity of binary and logical operators, i.e. if `a op b op c` is `(a op b) op c` or
+/// `a op (b op c)`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
 ast_struct! {
     pub struct ExprLogical {
         pub span: Span,
@@ -177,6 +221,20 @@ ast_node! {
     }
 }
 
+impl LogicalOperator {
+    /// Binding power and associativity of the operator, a higher binding power binds tighter.
+    ///
+    /// Note that `??` can not be mixed with `&&` and `||` without parentheses, even though it has
+    /// the lowest binding power.
+    pub fn binding_power(&self) -> (u8, Associativity) {
+        match self {
+            LogicalOperator::Coalesce => (1, Associativity::Left),
+            LogicalOperator::Or => (2, Associativity::Left),
+            LogicalOperator::And => (3, Associativity::Left),
+        }
+    }
+}
+
 ast_struct! {
     pub struct ExprUnary {
         pub span: Span,
@@ -288,13 +346,22 @@ ast_struct! {
     }
 }
 
-ast_node! {
+ast_enum! {
     pub enum ArrowFunctionBody {
         Expr(Box<Expr>),
         Body(Body),
     }
 }
 
+ast_struct! {
+    /// `do { ... }` expression from the do expressions proposal, only parsed with the
+    /// `do-expressions` feature of the parser.
+    pub struct ExprDo {
+        pub span: Span,
+        pub body: StmtBlock,
+    }
+}
+
 ast_struct! {
     pub struct ExprParenthesized {
         pub span: Span,
@@ -319,7 +386,7 @@ ast_struct! {
     }
 }
 
-ast_node! {
+ast_enum! {
     pub enum MemberObject {
         Expr(Box<Expr>),
         Super(Super),
@@ -332,7 +399,7 @@ ast_struct! {
     }
 }
 
-ast_node! {
+ast_enum! {
     pub enum MemberProperty {
         Ident(Ident),
         Expr(Box<Expr>),
@@ -356,10 +423,40 @@ ast_struct! {
     }
 }
 
-ast_node! {
+ast_enum! {
     pub enum Argument {
         Expr(Expr),
-        Spread(Expr),
+        Spread(Spread),
+    }
+}
+
+ast_struct! {
+    /// Spread element, `...argument`, shared by arguments, array literals and object literals.
+    #[serde(from = "SpreadRepr")]
+    pub struct Spread {
+        pub span: Span,
+        pub argument: Expr,
+    }
+}
+
+/// Serialized form of `Spread`, older trees stored the argument expression directly.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum SpreadRepr {
+    Spread { span: Span, argument: Expr },
+    Legacy(Expr),
+}
+
+impl From<SpreadRepr> for Spread {
+    fn from(repr: SpreadRepr) -> Self {
+        match repr {
+            SpreadRepr::Spread { span, argument } => Spread { span, argument },
+            // The span of the `...` token was never stored, the argument span is the closest.
+            SpreadRepr::Legacy(argument) => Spread {
+                span: crate::Spanned::span(&argument).clone(),
+                argument,
+            },
+        }
     }
 }
 
@@ -407,6 +504,12 @@ ast_node! {
         BitwiseXOr,
         #[from_string("|=")]
         BitwiseOr,
+        #[from_string("&&=")]
+        LogicalAnd,
+        #[from_string("||=")]
+        LogicalOr,
+        #[from_string("??=")]
+        Coalesce,
     }
 }
 
@@ -6,14 +6,20 @@ pub mod span;
 #[macro_use]
 pub mod expr;
 pub mod assignment;
+pub mod atom;
 pub mod class;
 pub mod literal;
 pub mod method;
+pub mod pretty;
+pub mod query;
 pub mod stmt;
+pub mod structural;
 
+use fajt_macros::{Pretty, Structural};
 use serde::{Deserialize, Serialize};
 
 pub use crate::assignment::*;
+pub use crate::atom::*;
 pub use crate::binding::*;
 pub use crate::class::*;
 pub use crate::expr::*;
@@ -26,20 +32,29 @@ pub use crate::stmt::*;
 pub enum SourceType {
     Module,
     Script,
+    /// Source that may be either a module or a script, it is parsed as a module if it is not a
+    /// valid script, i.e. when it has top level `import` or `export` declarations.
+    Unknown,
 }
 
 pub trait Spanned {
     fn span(&self) -> &Span;
 }
 
-#[derive(Debug, PartialOrd, PartialEq, Serialize, Deserialize)]
+impl<T: Spanned> Spanned for Box<T> {
+    fn span(&self) -> &Span {
+        (**self).span()
+    }
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Serialize, Deserialize, Structural, Pretty)]
 pub struct StmtList<T> {
     pub span: Span,
-    pub directives: Vec<LitString>,
+    pub directives: Vec<Directive>,
     pub body: Vec<T>,
 }
 
-#[derive(Debug, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialOrd, PartialEq, Serialize, Deserialize, Structural, Pretty)]
 pub enum Program {
     Script(StmtList<Stmt>),
     Module(StmtList<Stmt>),
@@ -60,19 +75,64 @@ impl Program {
             Program::Module(body) => &body.span,
         }
     }
+
+    /// The statement list of the program, regardless of source type.
+    pub fn stmt_list(&self) -> &StmtList<Stmt> {
+        match self {
+            Program::Script(stmt_list) | Program::Module(stmt_list) => stmt_list,
+        }
+    }
+
+    /// Top level statements, not including the directive prologue.
+    pub fn body(&self) -> &[Stmt] {
+        &self.stmt_list().body
+    }
+
+    /// Directives of the directive prologue, i.e. `"use strict"`.
+    pub fn directives(&self) -> &[Directive] {
+        &self.stmt_list().directives
+    }
+
+    /// `SourceType::Module` or `SourceType::Script`, never `SourceType::Unknown`.
+    pub fn source_type(&self) -> SourceType {
+        match self {
+            Program::Script(_) => SourceType::Script,
+            Program::Module(_) => SourceType::Module,
+        }
+    }
+
+    pub fn is_module(&self) -> bool {
+        matches!(self, Program::Module(_))
+    }
+
+    pub fn is_script(&self) -> bool {
+        matches!(self, Program::Script(_))
+    }
+}
+
+impl Spanned for Program {
+    fn span(&self) -> &Span {
+        Program::span(self)
+    }
+}
+
+impl<T> Spanned for StmtList<T> {
+    fn span(&self) -> &Span {
+        &self.span
+    }
 }
 
 ast_struct! {
     pub struct Ident {
         pub span: Span,
-        pub name: String,
+        pub name: Atom,
     }
 }
 
 impl Ident {
     pub fn new<N, S>(name: N, span: S) -> Self
     where
-        N: Into<String>,
+        N: Into<Atom>,
         S: Into<Span>,
     {
         Ident {
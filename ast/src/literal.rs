@@ -1,4 +1,4 @@
-use crate::{Expr, Ident, MethodDefinition, PropertyName, Span};
+use crate::{Expr, Ident, MethodDefinition, PropertyName, Span, Spread};
 
 ast_node! {
     pub enum Literal {
@@ -8,19 +8,29 @@ ast_node! {
         Number(LitNumber),
         Array(LitArray),
         Object(LitObject),
-        Regexp(String),
+        Regexp(LitRegexp),
         Template(LitTemplate),
     }
 }
 
 ast_node! {
     pub enum TemplatePart {
+        /// Raw source text of the part, escape sequences are kept as written.
         String(String),
         Expr(Box<Expr>),
     }
 }
 
 impl Literal {
+    /// Returns the string literal, or `None` if this is another kind of literal.
+    pub fn try_as_string(self) -> Option<LitString> {
+        if let Literal::String(string) = self {
+            Some(string)
+        } else {
+            None
+        }
+    }
+
     pub fn unwrap_string(self) -> LitString {
         if let Literal::String(string) = self {
             string
@@ -30,9 +40,20 @@ impl Literal {
     }
 }
 
+ast_node! {
+    /// Regular expression literal, the pattern is kept verbatim and is not compiled.
+    pub struct LitRegexp {
+        pub pattern: String,
+        pub flags: String,
+    }
+}
+
 ast_node! {
     pub struct LitString {
+        /// Value of the string, i.e. with escape sequences decoded.
         pub value: String,
+        /// Raw source text between the delimiters, escape sequences are kept as written.
+        pub raw: String,
         pub delimiter: char,
     }
 }
@@ -53,7 +74,7 @@ ast_node! {
     pub enum ArrayElement {
         Elision,
         Expr(Expr),
-        Spread(Expr),
+        Spread(Spread),
     }
 }
 
@@ -63,10 +84,10 @@ ast_node! {
     }
 }
 
-ast_node! {
+ast_enum! {
     pub enum PropertyDefinition {
         IdentRef(Ident),
-        Spread(Expr),
+        Spread(Spread),
         Named(NamedProperty),
         Method(MethodDefinition),
     }
@@ -11,6 +11,33 @@ macro_rules! ast_mapping {
         }
     ) => {
 
+        ast_enum! {
+            $(#[$enum_attr])*
+            $pub $enum $name {
+                $(
+                    $(#[$variant_attr])*
+                    $variant($member),
+                )*
+            }
+        }
+
+        $( ast_mapping_impl!($name, $variant, $member); )*
+    };
+}
+
+/// Enums where every variant wraps a node with a span, the enum gets its span from the variant.
+/// Unlike ast_mapping! this does not add any conversions, so variants may share the same type.
+macro_rules! ast_enum {
+    (
+        $(#[$enum_attr:meta])*
+        $pub:ident $enum:ident $name:ident {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident($member:ty)
+            ),* $(,)?
+        }
+    ) => {
+
         ast_node! {
             $(#[$enum_attr])*
             $pub $enum $name {
@@ -28,8 +55,6 @@ macro_rules! ast_mapping {
                 }
             }
         }
-
-        $( ast_mapping_impl!($name, $variant, $member); )*
     };
 }
 
@@ -64,7 +89,7 @@ macro_rules! ast_struct {
 }
 
 /// Implements common attributes for all different types in the ast tree.
-/// This is called implicitly for ast_struct! and ast_mapping!.
+/// This is called implicitly for ast_struct!, ast_enum! and ast_mapping!.
 ///
 /// This is mainly to handle traits that must be applied to the whole tree, for example Debug,
 /// Display, PartialEq.
@@ -73,9 +98,9 @@ macro_rules! ast_node {
         $(#[$meta:meta])*
         pub $( ($visibility:ident) )? $struct_or_enum:ident $name:ident $($rest:tt)*
     ) => {
-        $(#[$meta])*
         #[derive(Debug, Clone, PartialOrd, PartialEq)]
-        #[derive(serde::Serialize, serde::Deserialize)]
+        #[derive(serde::Serialize, serde::Deserialize, fajt_macros::Structural, fajt_macros::Pretty)]
+        $(#[$meta])*
         pub $( ($visibility) )? $struct_or_enum $name $($rest)*
     }
 }
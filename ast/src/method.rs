@@ -1,8 +1,10 @@
-use crate::{Body, FormalParameters, PropertyName, Span};
+use crate::{Body, Decorator, FormalParameters, PropertyName, Span};
 
 ast_struct! {
     pub struct MethodDefinition {
         pub span: Span,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub decorators: Vec<Decorator>,
         pub name: PropertyName,
         pub kind: MethodKind,
         pub parameters: FormalParameters,
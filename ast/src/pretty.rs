@@ -0,0 +1,175 @@
+//! Human readable tree representation of the AST, for debugging and inspection.
+//!
+//! Nodes are printed as S-expressions with the node kind and span, followed by the fields of the
+//! node. This is not code generation, see the `fajt_codegen` crate for that.
+//!
+//! ```
+//! # use fajt_ast::{Expr, Ident};
+//! let expr = Expr::IdentRef(Ident::new("a", (0, 1)));
+//! assert_eq!(expr.to_string(), r#"(IdentRef 0:1 name: "a")"#);
+//! ```
+use crate::{Atom, Expr, Program, Span, Stmt};
+use std::fmt;
+use std::fmt::{Display, Formatter, Write};
+
+/// Returns the tree representation of `node`.
+pub fn pretty<T: Pretty + ?Sized>(node: &T) -> String {
+    let mut output = String::new();
+    node.tree().write(&mut output, 0).unwrap();
+    output
+}
+
+/// Conversion of a node to its tree representation. Implemented for all nodes.
+pub trait Pretty {
+    fn tree(&self) -> Tree;
+}
+
+/// Intermediate representation of a node.
+pub enum Tree {
+    None,
+    Scalar(String),
+    Seq(Vec<Tree>),
+    Node {
+        kind: &'static str,
+        fields: Vec<(&'static str, Tree)>,
+        /// `true` if the kind is the name of an enum variant rather than a struct.
+        is_variant: bool,
+    },
+}
+
+impl Tree {
+    /// Names a struct node after the enum variant `kind` wrapping it. Nested enums keep the
+    /// innermost variant name, i.e. `MemberObject::Expr(Expr::IdentRef(_))` is printed as
+    /// `(IdentRef ...)`.
+    pub fn with_kind(self, kind: &'static str) -> Self {
+        match self {
+            Tree::Node {
+                is_variant: true, ..
+            } => self,
+            Tree::Node { fields, .. } => Tree::Node {
+                kind,
+                fields,
+                is_variant: true,
+            },
+            tree => Tree::Node {
+                kind,
+                fields: vec![("value", tree)],
+                is_variant: true,
+            },
+        }
+    }
+
+    fn is_scalar(&self) -> bool {
+        matches!(self, Tree::None | Tree::Scalar(_))
+            || matches!(self, Tree::Seq(items) if items.is_empty())
+    }
+
+    fn write(&self, output: &mut String, indent: usize) -> fmt::Result {
+        match self {
+            Tree::None => output.write_str("None"),
+            Tree::Scalar(value) => output.write_str(value),
+            Tree::Seq(items) if items.is_empty() => output.write_str("[]"),
+            Tree::Seq(items) => {
+                output.write_char('[')?;
+                for item in items {
+                    new_line(output, indent + 1)?;
+                    item.write(output, indent + 1)?;
+                }
+                output.write_char(']')
+            }
+            Tree::Node { kind, fields, .. } => {
+                output.write_char('(')?;
+                output.write_str(kind)?;
+
+                let mut fields = fields.iter().peekable();
+                if let Some((_, span)) = fields.next_if(|(name, _)| *name == "span") {
+                    output.write_char(' ')?;
+                    span.write(output, indent)?;
+                }
+
+                let fields: Vec<_> = fields.collect();
+                let single_line = fields.iter().all(|(_, value)| value.is_scalar());
+                for (name, value) in fields {
+                    if single_line {
+                        output.write_char(' ')?;
+                    } else {
+                        new_line(output, indent + 1)?;
+                    }
+
+                    write!(output, "{}: ", name)?;
+                    value.write(output, indent + 1)?;
+                }
+
+                output.write_char(')')
+            }
+        }
+    }
+}
+
+fn new_line(output: &mut String, indent: usize) -> fmt::Result {
+    output.write_char('\n')?;
+    for _ in 0..indent {
+        output.write_str("  ")?;
+    }
+    Ok(())
+}
+
+impl Pretty for Span {
+    fn tree(&self) -> Tree {
+        Tree::Scalar(format!("{}:{}", self.start, self.end))
+    }
+}
+
+/// Values without fields are printed with their `Debug` representation, i.e. strings are quoted.
+macro_rules! pretty_leaf {
+    ($($ty:ty),*) => {
+        $(
+            impl Pretty for $ty {
+                fn tree(&self) -> Tree {
+                    Tree::Scalar(format!("{:?}", self))
+                }
+            }
+        )*
+    };
+}
+
+pretty_leaf!(bool, char, usize, String, Atom);
+
+impl<T: Pretty + ?Sized> Pretty for Box<T> {
+    fn tree(&self) -> Tree {
+        (**self).tree()
+    }
+}
+
+impl<T: Pretty> Pretty for Option<T> {
+    fn tree(&self) -> Tree {
+        match self {
+            Some(value) => value.tree(),
+            None => Tree::None,
+        }
+    }
+}
+
+impl<T: Pretty> Pretty for Vec<T> {
+    fn tree(&self) -> Tree {
+        Tree::Seq(self.iter().map(Pretty::tree).collect())
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&pretty(self))
+    }
+}
+
+impl Display for Stmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&pretty(self))
+    }
+}
+
+impl Display for Program {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&pretty(self))
+    }
+}
@@ -0,0 +1,103 @@
+//! Queries on the AST, for example to find the node at a cursor position in an editor.
+
+use crate::traverse::{Traverse, Visitor};
+use crate::{Expr, Span, Spanned, Stmt};
+
+/// Expression or statement found by a query.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Node {
+    Expr(Expr),
+    Stmt(Stmt),
+}
+
+impl Spanned for Node {
+    fn span(&self) -> &Span {
+        match self {
+            Node::Expr(expr) => expr.span(),
+            Node::Stmt(stmt) => stmt.span(),
+        }
+    }
+}
+
+/// Result of `node_at_offset`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodeAtOffset {
+    /// The smallest expression or statement containing the offset.
+    pub node: Node,
+    /// Spans of the expressions and statements containing `node`, outermost first.
+    pub ancestors: Vec<Span>,
+}
+
+/// Returns the smallest expression or statement in `root` whose span contains `offset`, see
+/// `Span::contains`, together with the spans of its ancestors. Returns `None` if no expression or
+/// statement contains `offset`.
+///
+/// Only the found node is cloned, so the cost of a query does not depend on the depth of the node.
+///
+/// ```
+/// use fajt_ast::query::{node_at_offset, Node};
+/// use fajt_ast::{Expr, Span, Spanned};
+///
+/// let mut program = fajt_parser::parse_program("a + b;").unwrap();
+/// let found = node_at_offset(&mut program, 4).unwrap();
+/// assert!(matches!(found.node, Node::Expr(Expr::IdentRef(_))));
+/// assert_eq!(found.node.span(), &Span::new(4, 5));
+/// assert_eq!(found.ancestors, [Span::new(0, 6), Span::new(0, 5)]);
+/// ```
+pub fn node_at_offset<T: Traverse>(root: &mut T, offset: usize) -> Option<NodeAtOffset> {
+    let mut visitor = NodeAtOffsetVisitor {
+        offset,
+        path: Vec::new(),
+        node: None,
+    };
+    root.traverse(&mut visitor);
+
+    Some(NodeAtOffset {
+        node: visitor.node?,
+        ancestors: visitor.path,
+    })
+}
+
+/// Collects the spans of the expressions and statements containing the offset. The first node
+/// containing the offset to be exited is the smallest one, it is the only node cloned. Nodes not
+/// containing the offset are not traversed, since neither are their children.
+struct NodeAtOffsetVisitor {
+    offset: usize,
+    path: Vec<Span>,
+    node: Option<Node>,
+}
+
+impl NodeAtOffsetVisitor {
+    fn enter(&mut self, span: &Span) -> bool {
+        let contains = self.node.is_none() && span.contains(self.offset);
+        if contains {
+            self.path.push(span.clone());
+        }
+        contains
+    }
+
+    fn exit(&mut self, span: &Span, node: impl FnOnce() -> Node) {
+        if self.node.is_none() && span.contains(self.offset) {
+            self.path.pop();
+            self.node = Some(node());
+        }
+    }
+}
+
+impl Visitor for NodeAtOffsetVisitor {
+    fn enter_expr(&mut self, node: &mut Expr) -> bool {
+        self.enter(node.span())
+    }
+
+    fn exit_expr(&mut self, node: &mut Expr) {
+        self.exit(node.span(), || Node::Expr(node.clone()));
+    }
+
+    fn enter_stmt(&mut self, node: &mut Stmt) -> bool {
+        self.enter(node.span())
+    }
+
+    fn exit_stmt(&mut self, node: &mut Stmt) {
+        self.exit(node.span(), || Node::Stmt(node.clone()));
+    }
+}
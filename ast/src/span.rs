@@ -2,6 +2,8 @@ use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::Formatter;
 
+/// Location in the source, `start` and `end` are UTF-8 byte offsets, i.e. the span can be used to
+/// index the source `str` directly.
 #[derive(Debug, PartialOrd, PartialEq, Clone)]
 pub struct Span {
     pub start: usize,
@@ -24,9 +26,52 @@ impl Span {
         }
     }
 
+    /// Returns `true` for `Span::empty()`, i.e. a span without location. A zero length span at
+    /// another position is not empty, see `len`.
     pub fn is_empty(&self) -> bool {
         self.start == 0 && self.end == 0
     }
+
+    /// Number of bytes covered by this span.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the byte at `offset` is covered by this span, i.e. `end` is exclusive.
+    ///
+    /// ```
+    /// # use fajt_ast::Span;
+    /// let span = Span::new(4, 7);
+    /// assert!(span.contains(4) && span.contains(6));
+    /// assert!(!span.contains(7));
+    /// ```
+    pub fn contains(&self, offset: usize) -> bool {
+        self.start <= offset && offset < self.end
+    }
+
+    /// Returns the smallest span covering both spans, including anything between them.
+    ///
+    /// ```
+    /// # use fajt_ast::Span;
+    /// assert_eq!(Span::new(0, 3).merge(&Span::new(6, 9)), Span::new(0, 9));
+    /// ```
+    pub fn merge(&self, other: &Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    /// Returns the text of `source` covered by this span.
+    ///
+    /// ```
+    /// # use fajt_ast::Span;
+    /// assert_eq!(Span::new(4, 7).slice("var abc = 1;"), "abc");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the span is out of bounds or not on char boundaries of `source`, which can only
+    /// happen if it is not a span from parsing `source`.
+    pub fn slice<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.start..self.end]
+    }
 }
 
 impl Serialize for Span {
@@ -39,6 +39,24 @@ fn size_of_stmt() {
 }
 
 impl Stmt {
+    /// Returns the block statement, or `None` if this is another kind of statement.
+    pub fn try_as_block_stmt(self) -> Option<StmtBlock> {
+        if let Stmt::Block(block) = self {
+            Some(block)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the expression statement, or `None` if this is another kind of statement.
+    pub fn try_as_expr_stmt(self) -> Option<StmtExpr> {
+        if let Stmt::Expr(expr) = self {
+            Some(expr)
+        } else {
+            None
+        }
+    }
+
     pub fn unwrap_block_stmt(self) -> StmtBlock {
         if let Stmt::Block(block) = self {
             block
@@ -74,11 +92,31 @@ ast_struct! {
     }
 }
 
+ast_struct! {
+    /// Directive of a directive prologue, i.e. `"use strict";`. The span is the span of the string
+    /// literal.
+    pub struct Directive {
+        pub span: Span,
+        pub value: LitString,
+        /// `true` if the string literal contains an escape sequence or a line continuation.
+        pub escaped: bool,
+    }
+}
+
+impl Directive {
+    /// `true` if this is a Use Strict Directive, i.e. exactly `"use strict"` or `'use strict'`.
+    /// String literals with escape sequences or line continuations are not, even if the string
+    /// value is the same, e.g. `"use\u0020strict"`.
+    pub fn is_use_strict(&self) -> bool {
+        !self.escaped && self.value.value == "use strict"
+    }
+}
+
 ast_struct! {
     /// FunctionBody, ScriptBody or ModuleBody.
     pub struct Body {
         pub span: Span,
-        pub directives: Vec<LitString>,
+        pub directives: Vec<Directive>,
         pub statements: Vec<Stmt>,
     }
 }
@@ -255,7 +293,7 @@ ast_struct! {
     }
 }
 
-ast_node! {
+ast_enum! {
     pub enum ForInit {
         Expr(Box<Expr>),
         Declaration(StmtVariable),
@@ -281,7 +319,7 @@ ast_struct! {
     }
 }
 
-ast_node! {
+ast_enum! {
     pub enum ForDeclaration {
         Expr(Box<Expr>),
         AssignmentPattern(AssignmentPattern),
@@ -312,6 +350,50 @@ ast_struct! {
         pub namespace_binding: Option<Ident>,
         pub named_imports: Option<Vec<NamedImport>>,
         pub from: LitString,
+        pub attributes: Option<Box<ImportAttributes>>,
+    }
+}
+
+ast_struct! {
+    /// Attributes of an import or re-export, i.e. `with { type: "json" }`.
+    pub struct ImportAttributes {
+        pub span: Span,
+        pub keyword: ImportAttributesKeyword,
+        pub attributes: Vec<ImportAttribute>,
+    }
+}
+
+ast_node! {
+    /// Keyword that starts the import attributes, `assert` is the deprecated form of `with`.
+    #[derive(Copy, Eq)]
+    pub enum ImportAttributesKeyword {
+        With,
+        Assert,
+    }
+}
+
+ast_struct! {
+    pub struct ImportAttribute {
+        pub span: Span,
+        pub key: ImportAttributeKey,
+        pub value: LitString,
+    }
+}
+
+ast_node! {
+    pub enum ImportAttributeKey {
+        Ident(Ident),
+        String(LitString),
+    }
+}
+
+impl ImportAttributeKey {
+    /// The key as a string, regardless of whether it was written as an identifier or a string.
+    pub fn value(&self) -> &str {
+        match self {
+            ImportAttributeKey::Ident(ident) => &ident.name,
+            ImportAttributeKey::String(string) => &string.value,
+        }
     }
 }
 
@@ -324,11 +406,20 @@ ast_struct! {
     /// used for mapping within each module.
     pub struct NamedImport {
         pub span: Span,
-        pub name: Ident,
+        pub name: ModuleExportName,
         pub alias: Option<Ident>,
     }
 }
 
+ast_node! {
+    /// Name of an import or export as seen from other modules, string literals allows names that
+    /// are not valid identifiers, i.e. `export { a as "a b" }`.
+    pub enum ModuleExportName {
+        Ident(Ident),
+        String(LitString),
+    }
+}
+
 ast_mapping! {
     pub enum DeclExport {
         Decl(ExportDecl),
@@ -365,14 +456,16 @@ ast_struct! {
         pub span: Span,
         pub named_exports: Vec<NamedExport>,
         pub from: Option<LitString>,
+        pub attributes: Option<Box<ImportAttributes>>,
     }
 }
 
 ast_struct! {
     pub struct ExportNamespace {
         pub span: Span,
-        pub alias: Option<Ident>,
+        pub alias: Option<ModuleExportName>,
         pub from: LitString,
+        pub attributes: Option<Box<ImportAttributes>>,
     }
 }
 
@@ -385,7 +478,7 @@ ast_struct! {
     /// used for mapping within each module.
     pub struct NamedExport {
         pub span: Span,
-        pub name: Ident,
-        pub alias_of: Option<Ident>,
+        pub name: ModuleExportName,
+        pub alias_of: Option<ModuleExportName>,
     }
 }
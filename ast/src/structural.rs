@@ -0,0 +1,119 @@
+//! Span insensitive hashing and equality of AST nodes.
+//!
+//! The derived `PartialEq` of nodes compares spans, so the same code parsed from differently
+//! formatted source is not equal. `SpanInsensitive` wraps a node to hash and compare it by
+//! structure only, i.e. it can be used as key when deduplicating or caching expressions.
+//!
+//! ```
+//! # use fajt_ast::structural::SpanInsensitive;
+//! # use fajt_ast::{Expr, Ident};
+//! let a = Expr::IdentRef(Ident::new("a", (0, 1)));
+//! let b = Expr::IdentRef(Ident::new("a", (4, 5)));
+//! assert_ne!(a, b);
+//! assert_eq!(SpanInsensitive(a), SpanInsensitive(b));
+//! ```
+use crate::{Atom, Span};
+use std::hash::{Hash, Hasher};
+
+/// Equality and hashing by structure. Implemented for all nodes, `Span` compares equal and hashes
+/// to nothing, so nodes differing only in spans are equal and hash equally.
+pub trait Structural {
+    fn structural_eq(&self, other: &Self) -> bool;
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H);
+}
+
+/// Node that hashes and compares equal by structure, ignoring all spans.
+#[derive(Debug, Clone)]
+pub struct SpanInsensitive<T>(pub T);
+
+impl<T: Structural> Hash for SpanInsensitive<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.structural_hash(state);
+    }
+}
+
+impl<T: Structural> PartialEq for SpanInsensitive<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.structural_eq(&other.0)
+    }
+}
+
+impl<T: Structural> Eq for SpanInsensitive<T> {}
+
+/// Feeds the structure of `node` into `state`, nodes differing only in spans hash equally.
+pub fn hash_ignoring_spans<T: Structural + ?Sized, H: Hasher>(node: &T, state: &mut H) {
+    node.structural_hash(state);
+}
+
+/// Returns `true` if `a` and `b` are equal when ignoring all spans.
+pub fn eq_ignoring_spans<T: Structural + ?Sized>(a: &T, b: &T) -> bool {
+    a.structural_eq(b)
+}
+
+impl Structural for Span {
+    fn structural_eq(&self, _other: &Self) -> bool {
+        true
+    }
+
+    fn structural_hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Values without spans are compared and hashed as usual.
+macro_rules! structural_leaf {
+    ($($ty:ty),*) => {
+        $(
+            impl Structural for $ty {
+                fn structural_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+
+                fn structural_hash<H: Hasher>(&self, state: &mut H) {
+                    self.hash(state);
+                }
+            }
+        )*
+    };
+}
+
+structural_leaf!(bool, char, usize, String, Atom);
+
+impl<T: Structural + ?Sized> Structural for Box<T> {
+    fn structural_eq(&self, other: &Self) -> bool {
+        (**self).structural_eq(other)
+    }
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        (**self).structural_hash(state);
+    }
+}
+
+impl<T: Structural> Structural for Option<T> {
+    fn structural_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.structural_eq(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        self.is_some().hash(state);
+        if let Some(value) = self {
+            value.structural_hash(state);
+        }
+    }
+}
+
+impl<T: Structural> Structural for Vec<T> {
+    fn structural_eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.structural_eq(b))
+    }
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for value in self {
+            value.structural_hash(state);
+        }
+    }
+}
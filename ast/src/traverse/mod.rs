@@ -45,6 +45,7 @@ generate_fold_and_visit! {
             Call
             Class
             Conditional
+            Do
             Function
             IdentRef
             Literal
@@ -121,6 +122,16 @@ generate_fold_and_visit! {
             Computed
         }
 
+        ModuleExportName: (enter: enter_module_export_name, exit: exit_module_export_name) {
+            Ident
+            String
+        }
+
+        ImportAttributeKey: (enter: enter_import_attribute_key, exit: exit_import_attribute_key) {
+            Ident
+            String
+        }
+
         ClassElement: (enter: enter_class_element, exit: exit_class_element) {
             Method
         }
@@ -198,7 +209,13 @@ generate_fold_and_visit! {
         }
 
         AssignmentPattern: (enter: enter_assignment_pattern, exit: exit_assignment_pattern) {
-            // TODO
+            Array
+            Object
+        }
+
+        AssignmentProp: (enter: enter_assignment_prop, exit: exit_assignment_prop) {
+            Single
+            Named
         }
     }
 
@@ -212,6 +229,7 @@ generate_fold_and_visit! {
         }
 
         DeclClass: (enter: enter_class_decl, exit: exit_class_decl) {
+            decorators
             identifier
             super_class
             body
@@ -222,6 +240,7 @@ generate_fold_and_visit! {
             named_imports
             named_imports
             from
+            attributes
         }
 
         StmtExpr: (enter: enter_stmt_expr, exit: exit_stmt_expr) {
@@ -273,6 +292,10 @@ generate_fold_and_visit! {
             expression
         }
 
+        ExprDo: (enter: enter_do_expr, exit: exit_do_expr) {
+            body
+        }
+
         ExprLiteral: (enter: enter_literal_expr, exit: exit_literal_expr) {
             literal
         }
@@ -315,6 +338,10 @@ generate_fold_and_visit! {
             arguments
         }
 
+        Spread: (enter: enter_spread, exit: exit_spread) {
+            argument
+        }
+
         ExprArrowFunction: (enter: enter_arrow_function, exit: exit_arrow_function) {
             parameters
             body
@@ -330,6 +357,7 @@ generate_fold_and_visit! {
         }
 
         ExprClass: (enter: enter_class_expr, exit: exit_class_expr) {
+            decorators
             identifier
             super_class
             body
@@ -459,6 +487,32 @@ generate_fold_and_visit! {
             rest
         }
 
+        ArrayAssignmentPattern: (enter: enter_array_assignment_pattern, exit: exit_array_assignment_pattern) {
+            elements
+            rest
+        }
+
+        AssignmentElement: (enter: enter_assignment_element, exit: exit_assignment_element) {
+            target
+            initializer
+        }
+
+        ObjectAssignmentPattern: (enter: enter_object_assignment_pattern, exit: exit_object_assignment_pattern) {
+            props
+            rest
+        }
+
+        SingleNameAssignmentProp: (enter: enter_single_name_assignment_prop, exit: exit_single_name_assignment_prop) {
+            ident
+            initializer
+        }
+
+        NamedAssignmentProp: (enter: enter_named_assignment_prop, exit: exit_named_assignment_prop) {
+            name
+            value
+            initializer
+        }
+
         SingleNameBinding: (enter: enter_single_name_binding, exit: exit_single_name_binding) {
             ident
             initializer
@@ -475,11 +529,16 @@ generate_fold_and_visit! {
         }
 
         MethodDefinition: (enter: enter_method_definition, exit: exit_method_definition) {
+            decorators
             name
             parameters
             body
         }
 
+        Decorator: (enter: enter_decorator, exit: exit_decorator) {
+            expr
+        }
+
         CatchClause: (enter: enter_catch_clause, exit: exit_catch_clause) {
             parameter
             body
@@ -510,11 +569,22 @@ generate_fold_and_visit! {
         ExportNamed: (enter: enter_export_named, exit: exit_export_named) {
             named_exports
             from
+            attributes
         }
 
         ExportNamespace: (enter: enter_export_namespace, exit: exit_export_namespace) {
             alias
             from
+            attributes
+        }
+
+        ImportAttributes: (enter: enter_import_attributes, exit: exit_import_attributes) {
+            attributes
+        }
+
+        ImportAttribute: (enter: enter_import_attribute, exit: exit_import_attribute) {
+            key
+            value
         }
 
         NamedExport: (enter: enter_named_export, exit: exit_named_export) {
@@ -529,6 +599,10 @@ generate_fold_and_visit! {
 
         Ident: (enter: enter_ident, exit: exit_ident) {}
 
+        Directive: (enter: enter_directive, exit: exit_directive) {
+            value
+        }
+
         Body: (enter: enter_body, exit: exit_body) {
             directives
             statements
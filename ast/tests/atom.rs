@@ -0,0 +1,30 @@
+use fajt_ast::{Atom, Ident, Interner};
+
+#[test]
+fn interner_returns_shared_atoms() {
+    let mut interner = Interner::new();
+    let first = interner.intern("name");
+    let second = interner.intern("name");
+    let other = interner.intern("other");
+
+    assert!(first.ptr_eq(&second));
+    assert!(!first.ptr_eq(&other));
+    assert_eq!(interner.len(), 2);
+}
+
+#[test]
+fn atoms_compare_by_content() {
+    let atom = Atom::from("name");
+    assert_eq!(atom, Atom::from("name".to_owned()));
+    assert_eq!(atom, "name");
+    assert_eq!(atom.as_str(), "name");
+    assert!(!atom.ptr_eq(&Atom::from("name")));
+}
+
+#[test]
+fn atom_serializes_as_string() {
+    let ident = Ident::new("name", (0, 4));
+    let json = serde_json::to_string(&ident).unwrap();
+    assert_eq!(json, r#"{"span":"0:4","name":"name"}"#);
+    assert_eq!(serde_json::from_str::<Ident>(&json).unwrap(), ident);
+}
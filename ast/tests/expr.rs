@@ -1 +1,39 @@
 mod utils;
+
+use fajt_ast::{Expr, Literal, Stmt};
+use fajt_parser::{parse_expression, parse_statement};
+
+#[test]
+fn try_as_literal() {
+    let expr = parse_expression("'a'").unwrap();
+    let literal = expr.try_as_literal().unwrap();
+    assert_eq!(literal.literal.try_as_string().unwrap().value, "a");
+
+    let expr = parse_expression("a").unwrap();
+    assert_eq!(expr.try_as_literal(), None);
+
+    assert_eq!(Literal::Null.try_as_string(), None);
+}
+
+#[test]
+fn try_as_ident_ref() {
+    let expr = parse_expression("a").unwrap();
+    assert_eq!(expr.try_as_ident_ref().unwrap().name, "a");
+
+    let expr = parse_expression("1").unwrap();
+    assert_eq!(expr.try_as_ident_ref(), None);
+}
+
+#[test]
+fn try_as_stmt() {
+    let stmt = parse_statement("{}").unwrap();
+    assert!(stmt.clone().try_as_expr_stmt().is_none());
+    assert!(stmt.try_as_block_stmt().is_some());
+
+    let stmt = parse_statement("a;").unwrap();
+    assert!(matches!(
+        *stmt.clone().try_as_expr_stmt().unwrap().expr,
+        Expr::IdentRef(_)
+    ));
+    assert!(matches!(stmt, Stmt::Expr(_)));
+}
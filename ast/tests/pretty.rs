@@ -0,0 +1,74 @@
+use fajt_ast::{Expr, SourceType, Stmt};
+use fajt_parser::parse;
+
+#[test]
+fn binary_expression() {
+    let expr = parse::<Expr>("a + b", SourceType::Script).unwrap();
+    assert_eq!(
+        expr.to_string(),
+        concat!(
+            "(Binary 0:5\n",
+            "  operator: Plus\n",
+            "  left: (IdentRef 0:1 name: \"a\")\n",
+            "  right: (IdentRef 4:5 name: \"b\"))",
+        )
+    );
+}
+
+#[test]
+fn statement_with_lists() {
+    let stmt = parse::<Stmt>("f(a, [1]);", SourceType::Script).unwrap();
+    assert_eq!(
+        stmt.to_string(),
+        concat!(
+            "(Expr 0:10\n",
+            "  expr: (Call 0:9\n",
+            "    callee: (IdentRef 0:1 name: \"f\")\n",
+            "    arguments_span: 1:9\n",
+            "    arguments: [\n",
+            "      (IdentRef 2:3 name: \"a\")\n",
+            "      (Literal 5:8\n",
+            "        literal: (Array\n",
+            "          elements: [\n",
+            "            (Literal 6:7\n",
+            "              literal: (Number raw: \"1\"))]))]))",
+        )
+    );
+}
+
+#[test]
+fn empty_list_and_none() {
+    let stmt = parse::<Stmt>("function f() { return; }", SourceType::Script).unwrap();
+    assert_eq!(
+        stmt.to_string(),
+        concat!(
+            "(FunctionDecl 0:24\n",
+            "  asynchronous: false\n",
+            "  generator: false\n",
+            "  identifier: (Ident 9:10 name: \"f\")\n",
+            "  parameters: (FormalParameters 10:12 bindings: [] rest: None)\n",
+            "  body: (Body 13:24\n",
+            "    directives: []\n",
+            "    statements: [\n",
+            "      (Return 15:22 argument: None)]))",
+        )
+    );
+}
+
+#[test]
+fn strings_with_delimiters() {
+    let expr = parse::<Expr>(r#"f("a { b ( c, d: e", '] } )')"#, SourceType::Script).unwrap();
+    assert_eq!(
+        expr.to_string(),
+        concat!(
+            "(Call 0:29\n",
+            "  callee: (IdentRef 0:1 name: \"f\")\n",
+            "  arguments_span: 1:29\n",
+            "  arguments: [\n",
+            "    (Literal 2:19\n",
+            "      literal: (String value: \"a { b ( c, d: e\" raw: \"a { b ( c, d: e\" delimiter: '\"'))\n",
+            "    (Literal 21:28\n",
+            "      literal: (String value: \"] } )\" raw: \"] } )\" delimiter: '\\''))])",
+        )
+    );
+}
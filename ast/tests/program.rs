@@ -0,0 +1,28 @@
+use fajt_ast::{SourceType, Stmt};
+use fajt_parser::{parse_module, parse_script};
+
+#[test]
+fn module_accessors() {
+    let program = parse_module("'use strict';\nimport a from 'a';\nexport const b = a;").unwrap();
+
+    assert!(program.is_module());
+    assert!(!program.is_script());
+    assert_eq!(program.source_type(), SourceType::Module);
+
+    assert_eq!(program.directives().len(), 1);
+    assert!(program.directives()[0].is_use_strict());
+
+    assert_eq!(program.body().len(), 2);
+    assert!(matches!(program.body()[0], Stmt::ImportDecl(_)));
+    assert!(matches!(program.body()[1], Stmt::ExportDecl(_)));
+}
+
+#[test]
+fn script_accessors() {
+    let program = parse_script("a;").unwrap();
+
+    assert!(program.is_script());
+    assert_eq!(program.source_type(), SourceType::Script);
+    assert!(program.directives().is_empty());
+    assert_eq!(program.body().len(), 1);
+}
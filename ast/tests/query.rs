@@ -0,0 +1,52 @@
+use fajt_ast::query::{node_at_offset, Node};
+use fajt_ast::{Expr, MemberProperty, Span, Stmt};
+use fajt_parser::{parse_expression, parse_program};
+
+#[test]
+fn member_property_offset() {
+    let mut expr = parse_expression("a.b.c").unwrap();
+    let found = node_at_offset(&mut expr, 2).unwrap();
+
+    let Node::Expr(Expr::Member(member)) = &found.node else {
+        panic!("Expected member expression, got {:?}", found.node)
+    };
+    assert_eq!(member.span, Span::new(0, 3));
+    assert!(matches!(&member.property, MemberProperty::Ident(ident) if ident.name == "b"));
+
+    assert_eq!(found.ancestors, [Span::new(0, 5)]);
+}
+
+#[test]
+fn ancestors_from_statement() {
+    let mut program = parse_program("if (a) { f(b + c); }").unwrap();
+    let found = node_at_offset(&mut program, 15).unwrap();
+
+    assert!(matches!(found.node, Node::Expr(Expr::IdentRef(ref ident)) if ident.name == "c"));
+
+    assert_eq!(
+        found.ancestors,
+        [
+            Span::new(0, 20),
+            Span::new(7, 20),
+            Span::new(9, 18),
+            Span::new(9, 17),
+            Span::new(11, 16),
+        ]
+    );
+}
+
+#[test]
+fn offset_outside_nodes() {
+    let mut program = parse_program("a;  ").unwrap();
+    assert!(node_at_offset(&mut program, 3).is_none());
+    assert!(node_at_offset(&mut program, 100).is_none());
+}
+
+#[test]
+fn offset_in_statement_without_nested_node() {
+    let mut program = parse_program("if (a) {  }").unwrap();
+    let found = node_at_offset(&mut program, 8).unwrap();
+
+    assert!(matches!(found.node, Node::Stmt(Stmt::Block(_))));
+    assert_eq!(found.ancestors, [Span::new(0, 11)]);
+}
@@ -0,0 +1,127 @@
+use fajt_ast::{
+    Argument, ArrayElement, Expr, Literal, MemberObject, MemberProperty, Program,
+    PropertyDefinition, SourceType, Span, Spanned, Stmt,
+};
+use fajt_parser::parse;
+
+#[test]
+fn nested_expression_spans() {
+    let source = "a + f(b, [c, { d: e.g[h] }])";
+    let expr = parse::<Expr>(source, SourceType::Script).unwrap();
+    assert_eq!(expr.span(), &Span::new(0, 28));
+
+    let Expr::Binary(binary) = expr else { panic!() };
+    let Expr::Call(call) = *binary.right else {
+        panic!()
+    };
+    assert_eq!(call.span(), &Span::new(4, 28));
+    assert_eq!(call.arguments[0].span(), &Span::new(6, 7));
+
+    let argument = &call.arguments[1];
+    assert_eq!(argument.span(), &Span::new(9, 27));
+
+    let Argument::Expr(Expr::Literal(array)) = argument else {
+        panic!()
+    };
+    let Literal::Array(array) = &array.literal else {
+        panic!()
+    };
+    let ArrayElement::Expr(Expr::Literal(object)) = &array.elements[1] else {
+        panic!()
+    };
+    assert_eq!(object.span(), &Span::new(13, 26));
+
+    let Literal::Object(object) = &object.literal else {
+        panic!()
+    };
+    let property = &object.props[0];
+    assert_eq!(property.span(), &Span::new(15, 24));
+
+    let PropertyDefinition::Named(named) = property else {
+        panic!()
+    };
+    let Expr::Member(member) = &named.value else {
+        panic!()
+    };
+    assert_eq!(member.span(), &Span::new(18, 24));
+    assert_eq!(member.object.span(), &Span::new(18, 21));
+    assert_eq!(member.property.span(), &Span::new(22, 23));
+
+    let MemberObject::Expr(object) = &member.object else {
+        panic!()
+    };
+    let Expr::Member(inner) = object.as_ref() else {
+        panic!()
+    };
+    let MemberProperty::Ident(ident) = &inner.property else {
+        panic!()
+    };
+    assert_eq!(ident.span(), &Span::new(20, 21));
+}
+
+#[test]
+fn slice_identifier() {
+    let source = "a + abc";
+    let Expr::Binary(binary) = parse::<Expr>(source, SourceType::Script).unwrap() else {
+        panic!()
+    };
+    assert_eq!(binary.right.span().slice(source), "abc");
+    assert_eq!(binary.span.slice(source), source);
+}
+
+#[test]
+fn slice_after_multibyte_characters() {
+    let source = "'åäö' + '\u{1F600}'; ident";
+    let Program::Script(script) = parse::<Program>(source, SourceType::Script).unwrap() else {
+        panic!()
+    };
+
+    let Stmt::Expr(string) = &script.body[0] else {
+        panic!()
+    };
+    let Expr::Binary(binary) = string.expr.as_ref() else {
+        panic!()
+    };
+    assert_eq!(binary.left.span().slice(source), "'åäö'");
+
+    let Stmt::Expr(ident) = &script.body[1] else {
+        panic!()
+    };
+    assert_eq!(
+        ident.expr.span(),
+        &Span::new(source.find("ident").unwrap(), source.len())
+    );
+    assert_eq!(ident.expr.span().slice(source), "ident");
+}
+
+#[test]
+fn merge_disjoint_spans() {
+    let a = Span::new(2, 5);
+    let b = Span::new(8, 10);
+    assert_eq!(a.merge(&b), Span::new(2, 10));
+    assert_eq!(b.merge(&a), Span::new(2, 10));
+}
+
+#[test]
+fn merge_overlapping_spans() {
+    assert_eq!(Span::new(2, 8).merge(&Span::new(5, 10)), Span::new(2, 10));
+    assert_eq!(Span::new(2, 10).merge(&Span::new(4, 6)), Span::new(2, 10));
+    assert_eq!(Span::new(3, 3).merge(&Span::new(3, 7)), Span::new(3, 7));
+}
+
+#[test]
+fn contains_at_boundaries() {
+    let span = Span::new(4, 7);
+    assert!(!span.contains(3));
+    assert!(span.contains(4));
+    assert!(span.contains(6));
+    assert!(!span.contains(7));
+    assert!(!Span::new(4, 4).contains(4));
+}
+
+#[test]
+fn len() {
+    assert_eq!(Span::new(4, 7).len(), 3);
+    assert_eq!(Span::new(4, 4).len(), 0);
+    assert_eq!(Span::empty().len(), 0);
+}
@@ -0,0 +1,57 @@
+use fajt_ast::traverse::{Traverse, Visitor};
+use fajt_ast::{Argument, Expr, Span, Spread};
+use fajt_parser::{parse_expression, parse_program};
+
+#[derive(Default)]
+struct SpreadCounter {
+    spreads: Vec<Span>,
+}
+
+impl Visitor for SpreadCounter {
+    fn enter_spread(&mut self, node: &mut Spread) -> bool {
+        self.spreads.push(node.span.clone());
+        true
+    }
+}
+
+#[test]
+fn visitor_counts_spreads_in_all_contexts() {
+    let mut program = parse_program("f(...a, [...b], { ...c, d: [...e] });").unwrap();
+
+    let mut counter = SpreadCounter::default();
+    program.traverse(&mut counter);
+
+    assert_eq!(
+        counter.spreads,
+        vec![
+            Span::new(2, 6),
+            Span::new(9, 13),
+            Span::new(18, 22),
+            Span::new(28, 32),
+        ]
+    );
+}
+
+#[test]
+fn legacy_spread_json_deserializes() {
+    let json = r#"{"Spread":{"IdentRef":{"span":"5:6","name":"a"}}}"#;
+    let argument: Argument = serde_json::from_str(json).unwrap();
+
+    let expected = parse_expression("f(...a)").unwrap();
+    let Expr::Call(call) = expected else {
+        panic!("Expected call expression");
+    };
+
+    let Argument::Spread(spread) = argument else {
+        panic!("Expected spread argument");
+    };
+
+    // The legacy format has no span for the spread itself, it falls back to the argument span.
+    assert_eq!(spread.span, Span::new(5, 6));
+
+    let Argument::Spread(parsed) = &call.arguments[0] else {
+        panic!("Expected spread argument");
+    };
+    assert_eq!(spread.argument, parsed.argument);
+    assert_eq!(parsed.span, Span::new(2, 6));
+}
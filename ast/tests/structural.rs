@@ -0,0 +1,56 @@
+use fajt_ast::structural::{eq_ignoring_spans, SpanInsensitive};
+use fajt_parser::{parse_expression, parse_program};
+use std::collections::HashSet;
+
+#[test]
+fn differently_spaced_expressions_are_deduplicated() {
+    let a = parse_expression("a + b * f(c, 1)").unwrap();
+    let b = parse_expression("a+b*f( c,1 )").unwrap();
+    assert_ne!(a, b);
+
+    let mut set = HashSet::new();
+    set.insert(SpanInsensitive(a));
+    set.insert(SpanInsensitive(b));
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn different_expressions_are_not_equal() {
+    let pairs = [
+        ("a + b", "b + a"),
+        ("a + b", "a - b"),
+        ("a", "(a)"),
+        ("f(a)", "f(a, b)"),
+        ("'a'", "\"a\""),
+        ("[a, b]", "[[a, b]]"),
+        ("new A", "new A()"),
+    ];
+
+    for (a, b) in pairs {
+        let a = parse_expression(a).unwrap();
+        let b = parse_expression(b).unwrap();
+        assert!(!eq_ignoring_spans(&a, &b), "{:?} == {:?}", a, b);
+
+        let mut set = HashSet::new();
+        set.insert(SpanInsensitive(a));
+        set.insert(SpanInsensitive(b));
+        assert_eq!(set.len(), 2);
+    }
+}
+
+#[test]
+fn programs_are_equal_ignoring_spans() {
+    let a = parse_program("if (a) {\n    b();\n}\n").unwrap();
+    let b = parse_program("if(a){b()}").unwrap();
+    assert!(eq_ignoring_spans(&a, &b));
+    assert_eq!(SpanInsensitive(a), SpanInsensitive(b));
+}
+
+#[test]
+fn spans_not_named_span_are_ignored() {
+    // Calls have an `arguments_span` besides their `span`.
+    let a = parse_expression("f(a)(b)").unwrap();
+    let b = parse_expression("f( a )(  b  )").unwrap();
+    assert!(eq_ignoring_spans(&a, &b));
+    assert_eq!(SpanInsensitive(a), SpanInsensitive(b));
+}
@@ -1,18 +1,51 @@
 use fajt_ast::traverse::{Traverse, Visitor};
 use fajt_ast::*;
 use std::cell::Cell;
+use std::fmt;
 use std::rc::Rc;
 
 pub fn generate_code<T: Traverse>(program: &mut T, ctx: GeneratorContext) -> String {
+    let mut data = String::new();
+    generate_code_into(program, ctx, &mut data).expect("Writing to a String does not fail");
+    data
+}
+
+/// Generates code like `generate_code` but writes it to `out`. Code of a program is written one
+/// top level statement at a time, so the whole output is never held in memory.
+///
+/// ```
+/// use fajt_codegen::{generate_code_into, GeneratorContext};
+///
+/// let mut program = fajt_parser::parse_program("a; b;").unwrap();
+/// let mut out = String::new();
+/// generate_code_into(&mut program, GeneratorContext::new(), &mut out).unwrap();
+/// assert_eq!(out, "a;\nb;\n");
+/// ```
+pub fn generate_code_into<T: Traverse, W: fmt::Write>(
+    program: &mut T,
+    ctx: GeneratorContext,
+    out: &mut W,
+) -> fmt::Result {
     let mut data = String::new();
     let mut codegen = CodeGenerator::new(&mut data, ctx);
+    codegen.out = Some(out);
     program.traverse(&mut codegen);
-    data
+    codegen.flush(codegen.data.len());
+    codegen.result
 }
 
 #[derive(Clone)]
 pub struct GeneratorContext {
     pub minified: bool,
+    /// Emit parentheses from the source, if `false` only parentheses required for the
+    /// expression to keep its meaning are emitted.
+    pub preserve_parens: bool,
+    /// End formatted output with a new line, minified output never ends with a new line.
+    pub final_newline: bool,
+    /// Semicolon style, defaults to `Always` for formatted and `AsNeeded` for minified output.
+    pub semicolons: Option<SemicolonStyle>,
+    /// Line ending of formatted output.
+    pub line_ending: LineEnding,
     indent_size: usize,
     indent: usize,
     align: Option<usize>,
@@ -22,12 +55,24 @@ impl GeneratorContext {
     pub fn new() -> Self {
         GeneratorContext {
             minified: false,
+            preserve_parens: true,
+            final_newline: true,
+            semicolons: None,
+            line_ending: LineEnding::Lf,
             indent_size: 4,
             indent: 0,
             align: None,
         }
     }
 
+    fn semicolons(&self) -> SemicolonStyle {
+        self.semicolons.unwrap_or(if self.minified {
+            SemicolonStyle::AsNeeded
+        } else {
+            SemicolonStyle::Always
+        })
+    }
+
     fn indentation(&self) -> usize {
         if let Some(align) = self.align {
             align
@@ -43,9 +88,47 @@ impl Default for GeneratorContext {
     }
 }
 
+/// Whether statements end with a semicolon where it is optional.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SemicolonStyle {
+    /// All statements that may end with a semicolon do.
+    Always,
+    /// Semicolons are left out after the last statement of a program or block.
+    AsNeeded,
+}
+
+/// Character sequence ending each line of formatted output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    /// `\n`, used on Unix-like systems.
+    #[default]
+    Lf,
+    /// `\r\n`, used on Windows.
+    Crlf,
+}
+
+impl LineEnding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::Crlf => "\r\n",
+        }
+    }
+}
+
+/// Precedence of unary and await expressions, see `CodeGenerator::precedence`.
+const UNARY_PRECEDENCE: u8 = 14;
+
+/// Precedence of member, call and primary expressions, see `CodeGenerator::precedence`.
+const MEMBER_PRECEDENCE: u8 = 16;
+
 struct Index {
     last_new_line: Cell<usize>,
     last_block_start: Cell<usize>,
+    /// Bytes already written to the output, positions include them.
+    flushed: Cell<usize>,
+    /// End position of the last empty statement, its `;` is never optional.
+    last_empty_stmt: Cell<Option<usize>>,
 }
 
 impl Index {
@@ -53,6 +136,8 @@ impl Index {
         Index {
             last_new_line: Cell::new(0),
             last_block_start: Cell::new(0),
+            flushed: Cell::new(0),
+            last_empty_stmt: Cell::new(None),
         }
     }
 
@@ -74,10 +159,14 @@ impl Index {
 }
 
 struct CodeGenerator<'a> {
+    /// Output that is not yet written to `out`.
     data: &'a mut String,
     ctx: GeneratorContext,
     index: Rc<Index>,
     skip_next_separation: bool,
+    /// Where output is written when flushed, only set for the root generator.
+    out: Option<&'a mut dyn fmt::Write>,
+    result: fmt::Result,
 }
 
 impl<'a> CodeGenerator<'a> {
@@ -87,9 +176,40 @@ impl<'a> CodeGenerator<'a> {
             ctx,
             index: Rc::new(Index::new()),
             skip_next_separation: false,
+            out: None,
+            result: Ok(()),
         }
     }
 
+    /// Writes pending output to `out`, except what follows the last non identifier character.
+    /// That part is kept since whether the next token must be separated depends on it. A `\r\n`
+    /// line ending is kept whole so it can still be removed.
+    fn flush_pending(&mut self) {
+        let mut keep_from = self
+            .data
+            .rfind(|c: char| !is_identifier_part(c))
+            .unwrap_or(0);
+        if self.data[..keep_from].ends_with('\r') {
+            keep_from -= 1;
+        }
+        self.flush(keep_from);
+    }
+
+    /// Writes the first `len` bytes of pending output to `out`.
+    fn flush(&mut self, len: usize) {
+        let Some(out) = self.out.as_mut() else {
+            return;
+        };
+
+        if len == 0 || self.result.is_err() {
+            return;
+        }
+
+        self.result = out.write_str(&self.data[..len]);
+        self.data.drain(..len);
+        self.index.flushed.set(self.index.flushed.get() + len);
+    }
+
     fn comma_separated<I>(&mut self, items: &mut [I])
     where
         I: Traverse,
@@ -174,6 +294,14 @@ impl<'a> CodeGenerator<'a> {
         self.end_block();
     }
 
+    fn decorators(&mut self, decorators: &mut [Decorator]) {
+        for decorator in decorators {
+            self.char('@');
+            decorator.expr.traverse(self);
+            self.space();
+        }
+    }
+
     fn function<I, P, B>(
         &mut self,
         asynchronous: bool,
@@ -204,6 +332,177 @@ impl<'a> CodeGenerator<'a> {
         body.traverse(self);
     }
 
+    /// Generates the elements and rest of an array binding or assignment pattern.
+    fn array_pattern<E, R>(&mut self, elements: &mut [Option<E>], rest: &mut Option<R>)
+    where
+        E: Traverse,
+        R: Traverse,
+    {
+        let spaced = !elements.is_empty() || rest.is_some();
+        self.parenthesize('[', spaced, |s| {
+            let mut iter = elements.iter_mut().peekable();
+            while let Some(element) = iter.next() {
+                element.traverse(s);
+
+                if iter.peek().is_some() || element.is_none() {
+                    s.char(',');
+                }
+
+                if iter.peek().is_some() {
+                    s.space();
+                }
+            }
+
+            if let Some(rest) = rest.as_mut() {
+                // A trailing elision already ends with a comma.
+                match elements.last() {
+                    Some(Some(_)) => {
+                        s.char(',');
+                        s.space();
+                    }
+                    Some(None) => s.space(),
+                    None => {}
+                }
+
+                s.string("...");
+                rest.traverse(s);
+            }
+        });
+    }
+
+    /// Generates `expr`, wrapped in parentheses if `parenthesize` is set.
+    fn operand(&mut self, expr: &mut Expr, parenthesize: bool) {
+        if parenthesize {
+            self.parenthesize('(', false, |s| expr.traverse(s));
+        } else {
+            expr.traverse(self);
+        }
+    }
+
+    /// Object of a member expression or callee of a call, e.g. `(await a).b` or `(a + b)()`.
+    fn member_operand(&mut self, expr: &mut Expr) {
+        // Parentheses in `(1).a` are required, `1.a` is read as a number.
+        let is_parenthesized_number = matches!(
+            expr,
+            Expr::Parenthesized(parenthesized) if matches!(
+                &*parenthesized.expression,
+                Expr::Literal(ExprLiteral { literal: Literal::Number(_), .. })
+            )
+        );
+        if is_parenthesized_number {
+            expr.traverse(self);
+            return;
+        }
+
+        let expr = self.without_parens(expr);
+        // `(new a).b` is not `new a.b` and `(a?.b).c` does not short-circuit like `a?.b.c`.
+        let parenthesize = Self::precedence(expr) < MEMBER_PRECEDENCE
+            || expr.is_new_without_arguments()
+            || matches!(expr, Expr::OptionalMember(_) | Expr::OptionalCall(_));
+        self.operand(expr, parenthesize);
+    }
+
+    /// Callee of a new expression, e.g. `new (a().b)` or `new a.b`.
+    fn new_callee(&mut self, expr: &mut Expr) {
+        let expr = self.without_parens(expr);
+        let parenthesize =
+            Self::precedence(expr) < MEMBER_PRECEDENCE || self.new_callee_needs_parens(expr);
+        self.operand(expr, parenthesize);
+    }
+
+    /// Returns `true` if `expr` must be parenthesized as callee of a new expression, even though
+    /// it is a member expression. A call in the member chain would be the call of the new
+    /// expression, i.e. `new (a().b)` is not `new a().b`, and so would the arguments following
+    /// `new a` in `new (new a)()`. Optional chains are not allowed as callee.
+    fn new_callee_needs_parens(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Call(_) | Expr::OptionalCall(_) | Expr::OptionalMember(_) => true,
+            Expr::New(new) => new.arguments_span.is_none(),
+            Expr::Member(ExprMember {
+                object: MemberObject::Expr(object),
+                ..
+            }) => self.new_callee_needs_parens(object),
+            Expr::TaggedTemplate(tagged) => self.new_callee_needs_parens(&tagged.callee),
+            // Parentheses in the member chain are dropped, i.e. `new (a().b).c`.
+            Expr::Parenthesized(parenthesized) => {
+                !self.ctx.preserve_parens && self.new_callee_needs_parens(&parenthesized.expression)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `expr` without its parentheses, unless they are preserved. Parentheses around
+    /// object literals, functions and classes are kept, they may be needed to not start a
+    /// statement with `{`, `function` or `class`. The same goes for expressions starting with
+    /// `let`, i.e. `(let)[0]`.
+    fn without_parens<'e>(&self, expr: &'e mut Expr) -> &'e mut Expr {
+        let is_droppable = matches!(
+            expr,
+            Expr::Parenthesized(parenthesized) if !is_statement_like(&parenthesized.expression)
+                && !starts_with_let(&parenthesized.expression)
+        );
+        if self.ctx.preserve_parens || !is_droppable {
+            return expr;
+        }
+
+        match expr {
+            Expr::Parenthesized(parenthesized) => {
+                self.without_parens(&mut parenthesized.expression)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generates `left` and `right` operands of a binary or logical expression.
+    fn binary_operands(
+        &mut self,
+        left: &mut Expr,
+        right: &mut Expr,
+        operator: &str,
+        (parenthesize_left, parenthesize_right): (bool, bool),
+    ) {
+        self.operand(left, parenthesize_left);
+        self.space();
+        self.string(operator);
+        self.space();
+        self.operand(right, parenthesize_right);
+    }
+
+    /// Returns which operands of an operator with `binding_power` that must be parenthesized.
+    /// Operands that bind equally strong are parenthesized on the side that the operator's
+    /// associativity does not group, i.e. `a - (b - c)` and `(a ** b) ** c`.
+    fn operands_needs_parens(
+        left: &Expr,
+        right: &Expr,
+        (power, associativity): (u8, Associativity),
+    ) -> (bool, bool) {
+        let precedence = power + 1;
+        let left_precedence = Self::precedence(left);
+        let right_precedence = Self::precedence(right);
+        match associativity {
+            Associativity::Left => (left_precedence < precedence, right_precedence <= precedence),
+            Associativity::Right => (left_precedence <= precedence, right_precedence < precedence),
+        }
+    }
+
+    /// Precedence of an expression, operands with lower precedence than what an operator expects
+    /// must be parenthesized. Binary and logical expressions are their binding power + 1, the
+    /// lower levels are sequence and assignment expressions.
+    fn precedence(expr: &Expr) -> u8 {
+        match expr {
+            Expr::Sequence(_) => 0,
+            Expr::Assignment(_)
+            | Expr::ArrowFunction(_)
+            | Expr::Yield(_)
+            | Expr::Conditional(_) => 1,
+            Expr::Binary(binary) => binary.operator.binding_power().0 + 1,
+            Expr::Logical(logical) => logical.operator.binding_power().0 + 1,
+            Expr::Unary(_) | Expr::Await(_) => UNARY_PRECEDENCE,
+            Expr::Update(_) => 15,
+            _ => MEMBER_PRECEDENCE,
+        }
+    }
+
     #[inline]
     fn quote(&mut self, delimiter: char, string: &str) {
         self.char(delimiter);
@@ -242,6 +541,8 @@ impl CodeGenerator<'_> {
             data: self.data,
             skip_next_separation: self.skip_next_separation,
             index: self.index.clone(),
+            out: None,
+            result: Ok(()),
             ctx: GeneratorContext {
                 indent: self.ctx.indent + 1,
                 ..self.ctx
@@ -255,6 +556,8 @@ impl CodeGenerator<'_> {
             data: self.data,
             skip_next_separation: self.skip_next_separation,
             index: self.index.clone(),
+            out: None,
+            result: Ok(()),
             ctx: GeneratorContext {
                 align: Some(align),
                 ..self.ctx
@@ -267,6 +570,8 @@ impl CodeGenerator<'_> {
             data: self.data,
             skip_next_separation: self.skip_next_separation,
             index: self.index.clone(),
+            out: None,
+            result: Ok(()),
             ctx: GeneratorContext {
                 align: None,
                 ..self.ctx
@@ -287,13 +592,24 @@ impl CodeGenerator<'_> {
         false
     }
 
+    /// Remove line ending at the end of output, if any.
+    fn remove_line_ending(&mut self) -> bool {
+        let line_ending = self.ctx.line_ending.as_str();
+        if self.data.ends_with(line_ending) {
+            self.data.truncate(self.data.len() - line_ending.len());
+            return true;
+        }
+
+        false
+    }
+
     fn last(&self) -> Option<char> {
         self.data.chars().last()
     }
 
     /// Current byte position from start.
     fn pos(&self) -> usize {
-        self.data.len()
+        self.index.flushed.get() + self.data.len()
     }
 
     /// Current byte position from start of current line.
@@ -311,6 +627,11 @@ impl CodeGenerator<'_> {
     fn string(&mut self, str: &str) {
         self.indent();
 
+        // `<!--` starts a HTML-like comment in scripts, i.e. `a < !--b` must not be `a<!--b`.
+        if str.starts_with("--") && self.data.ends_with("<!") {
+            self.data.insert(self.data.len() - 1, ' ');
+        }
+
         // Make sure we separate keywords
         if self.must_add_space_before(str) {
             self.data.push(' ');
@@ -335,19 +656,34 @@ impl CodeGenerator<'_> {
     fn end_block(&mut self) {
         if self.at_block_start() && !self.ctx.minified {
             // Empty blocks should not have line break.
-            if self.remove_last('\n') {
+            if self.remove_line_ending() {
                 self.index.set_new_line(0); // Reset new line index since we are no longer at new line.
             }
         }
 
-        if self.ctx.minified {
+        if self.ctx.semicolons() == SemicolonStyle::AsNeeded {
             // No ';' is necessary before a '}'.
-            self.remove_last(';');
+            self.remove_optional_semicolon();
         }
 
         self.char('}');
     }
 
+    /// Removes the semicolon ending the output, if it is not needed.
+    fn remove_optional_semicolon(&mut self) {
+        let new_line = self.remove_line_ending();
+
+        // The `;` of an empty statement is the statement itself, i.e. `if(a);`.
+        if self.index.last_empty_stmt.get() != Some(self.pos()) {
+            self.remove_last(';');
+        }
+
+        if new_line {
+            self.data.push_str(self.ctx.line_ending.as_str());
+            self.index.set_new_line(self.pos());
+        }
+    }
+
     fn at_block_start(&self) -> bool {
         self.index.last_block_start() == self.pos()
     }
@@ -366,7 +702,8 @@ impl CodeGenerator<'_> {
 
     fn new_line(&mut self) {
         if !self.ctx.minified {
-            self.char('\n');
+            self.indent();
+            self.data.push_str(self.ctx.line_ending.as_str());
             self.index.set_new_line(self.pos());
         }
     }
@@ -380,23 +717,53 @@ impl CodeGenerator<'_> {
     }
 
     /// Check if a space must be added before adding str to avoid merging keywords or identifiers.
+    /// Returns `true` if `str` would merge with the previous output into a different token
+    /// sequence, i.e. `return a` to `returna`, `a + +b` to `a++b` or `1 .a` to `1.a`.
     fn must_add_space_before(&self, str: &str) -> bool {
-        let last_is_alphanumeric = self.last().map(char::is_alphanumeric).unwrap_or(false);
-        let first_is_alphanumeric = str
-            .chars()
-            .next()
-            .map(char::is_alphanumeric)
-            .unwrap_or(false);
-        last_is_alphanumeric && first_is_alphanumeric
+        let (last, first) = match (self.last(), str.chars().next()) {
+            (Some(last), Some(first)) => (last, first),
+            _ => return false,
+        };
+
+        match (last, first) {
+            ('+', '+') | ('-', '-') => true,
+            // `//` and `/*` starts a comment.
+            ('/', '/' | '*') => true,
+            // `-->` ends a HTML-like comment in scripts.
+            ('-', '>') => self.data.ends_with("--"),
+            (_, '.') => self.ends_with_integer_literal(),
+            _ => is_identifier_part(last) && is_identifier_part(first),
+        }
+    }
+
+    /// Returns `true` if the output ends with an integer literal, a `.` directly after it would be
+    /// read as a decimal point. Integer literals may contain `_` separators, i.e. `1_000`.
+    fn ends_with_integer_literal(&self) -> bool {
+        let data = self.data.as_str();
+        let word_start = data
+            .rfind(|c: char| !is_identifier_part(c))
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let word = &data[word_start..];
+
+        word.starts_with(|c: char| c.is_ascii_digit())
+            && word.chars().all(|c| c.is_ascii_digit() || c == '_')
+            && !data[..word_start].ends_with('.')
     }
 }
 
+fn is_identifier_part(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '$' || ch == '_'
+}
+
 impl Visitor for CodeGenerator<'_> {
     fn exit_program(&mut self, _node: &mut Program) {
-        if self.ctx.minified && self.remove_last(';') && matches!(self.last(), Some(')')) {
-            // Don't remove semi colon if that render the last char a ')' since that could
-            // result in invalid code like `for(true)`, `if(true)`, ...
-            self.char(';');
+        if self.ctx.semicolons() == SemicolonStyle::AsNeeded {
+            self.remove_optional_semicolon();
+        }
+
+        if !self.ctx.minified && !self.ctx.final_newline {
+            self.remove_line_ending();
         }
     }
 
@@ -406,7 +773,11 @@ impl Visitor for CodeGenerator<'_> {
             self.char(';');
             self.new_line();
         }
-        node.body.traverse(self);
+
+        for stmt in &mut node.body {
+            self.flush_pending();
+            stmt.traverse(self);
+        }
         false
     }
 
@@ -520,7 +891,13 @@ impl Visitor for CodeGenerator<'_> {
         self.string("=>");
         self.space();
 
-        node.body.traverse(self);
+        match &mut node.body {
+            // A concise body starting with `{` would be read as a block body.
+            ArrowFunctionBody::Expr(expr) if starts_with_object_literal(expr) => {
+                self.parenthesize('(', false, |s| expr.traverse(s));
+            }
+            body => body.traverse(self),
+        }
 
         false
     }
@@ -528,7 +905,9 @@ impl Visitor for CodeGenerator<'_> {
     fn enter_await_expr(&mut self, node: &mut ExprAwait) -> bool {
         self.string("await");
         self.space();
-        node.argument.traverse(self);
+        let argument = self.without_parens(&mut node.argument);
+        let parenthesize = Self::precedence(argument) < UNARY_PRECEDENCE;
+        self.operand(argument, parenthesize);
         false
     }
 
@@ -576,11 +955,17 @@ impl Visitor for CodeGenerator<'_> {
         false
     }
 
-    fn exit_for_init(&mut self, node: &mut ForInit) {
-        if matches!(node, ForInit::Declaration(_)) {
-            // Variable statements ends with semicolon, don't want that inside for syntax.
-            self.remove_last(';');
+    fn enter_for_init(&mut self, node: &mut ForInit) -> bool {
+        if let ForInit::Declaration(declaration) = node {
+            // Unlike variable statements, the declarations are kept on one line without semicolon.
+            let kind_str = declaration.kind.to_string();
+            self.string(&kind_str);
+            self.space();
+            self.comma_separated(&mut declaration.declarations);
+            return false;
         }
+
+        true
     }
 
     fn enter_for_in_stmt(&mut self, node: &mut StmtForIn) -> bool {
@@ -646,7 +1031,9 @@ impl Visitor for CodeGenerator<'_> {
 
     fn enter_unary_expr(&mut self, node: &mut ExprUnary) -> bool {
         self.string(&node.operator.to_string());
-        node.argument.traverse(self);
+        let argument = self.without_parens(&mut node.argument);
+        let parenthesize = Self::precedence(argument) < UNARY_PRECEDENCE;
+        self.operand(argument, parenthesize);
         false
     }
 
@@ -662,20 +1049,47 @@ impl Visitor for CodeGenerator<'_> {
     }
 
     fn enter_binary_expr(&mut self, node: &mut ExprBinary) -> bool {
-        node.left.traverse(self);
-        self.space();
-        self.string(&node.operator.to_string());
-        self.space();
-        node.right.traverse(self);
+        let left = self.without_parens(&mut node.left);
+        let right = self.without_parens(&mut node.right);
+        let (mut parenthesize_left, parenthesize_right) =
+            Self::operands_needs_parens(left, right, node.operator.binding_power());
+
+        // Unary expressions are not allowed as base of `**`, i.e. `(-a) ** b`.
+        if node.operator == BinaryOperator::Exponent {
+            parenthesize_left |= matches!(left, Expr::Unary(_) | Expr::Await(_));
+        }
+
+        let operator = node.operator.to_string();
+        self.binary_operands(
+            left,
+            right,
+            &operator,
+            (parenthesize_left, parenthesize_right),
+        );
         false
     }
 
     fn enter_logical_expr(&mut self, node: &mut ExprLogical) -> bool {
-        node.left.traverse(self);
-        self.space();
-        self.string(&node.operator.to_string());
-        self.space();
-        node.right.traverse(self);
+        let left = self.without_parens(&mut node.left);
+        let right = self.without_parens(&mut node.right);
+        let (parenthesize_left, parenthesize_right) =
+            Self::operands_needs_parens(left, right, node.operator.binding_power());
+
+        // `??` can not be mixed with `&&` or `||` without parentheses, regardless of precedence.
+        let is_mixed_coalesce = |operand: &Expr| match operand {
+            Expr::Logical(operand) => {
+                (operand.operator == LogicalOperator::Coalesce)
+                    != (node.operator == LogicalOperator::Coalesce)
+            }
+            _ => false,
+        };
+        let parenthesize = (
+            parenthesize_left || is_mixed_coalesce(left),
+            parenthesize_right || is_mixed_coalesce(right),
+        );
+
+        let operator = node.operator.to_string();
+        self.binary_operands(left, right, &operator, parenthesize);
         false
     }
 
@@ -691,7 +1105,15 @@ impl Visitor for CodeGenerator<'_> {
         match node {
             Callee::Super => self.string("super"),
             Callee::Import => self.string("import"),
-            Callee::Expr(expr) => expr.traverse(self),
+            Callee::Expr(expr) => self.member_operand(expr),
+        }
+        false
+    }
+
+    fn enter_member_object(&mut self, node: &mut MemberObject) -> bool {
+        match node {
+            MemberObject::Expr(expr) => self.member_operand(expr),
+            MemberObject::Super(_) => return true,
         }
         false
     }
@@ -740,7 +1162,7 @@ impl Visitor for CodeGenerator<'_> {
     fn enter_member_property(&mut self, node: &mut MemberProperty) -> bool {
         match node {
             MemberProperty::Ident(i) => {
-                self.char('.');
+                self.string(".");
                 i.traverse(self)
             }
             MemberProperty::Expr(expr) => {
@@ -752,18 +1174,19 @@ impl Visitor for CodeGenerator<'_> {
         false
     }
 
-    fn enter_argument(&mut self, node: &mut Argument) -> bool {
-        match node {
-            Argument::Expr(e) => e.traverse(self),
-            Argument::Spread(e) => {
-                self.string("...");
-                e.traverse(self);
-            }
-        }
+    fn enter_do_expr(&mut self, node: &mut ExprDo) -> bool {
+        self.string("do");
+        self.space();
+        node.body.traverse(self);
         false
     }
 
     fn enter_parenthesized_expr(&mut self, node: &mut ExprParenthesized) -> bool {
+        if !self.ctx.preserve_parens && is_redundant_parens(&node.expression) {
+            node.expression.traverse(self);
+            return false;
+        }
+
         self.parenthesize('(', false, |s| {
             node.expression.traverse(s);
         });
@@ -794,16 +1217,20 @@ impl Visitor for CodeGenerator<'_> {
     }
 
     fn enter_class_decl(&mut self, node: &mut DeclClass) -> bool {
+        self.decorators(&mut node.decorators);
         self.class(&mut node.identifier, &mut node.super_class, &mut node.body);
         false
     }
 
     fn enter_class_expr(&mut self, node: &mut ExprClass) -> bool {
+        self.decorators(&mut node.decorators);
         self.class(&mut node.identifier, &mut node.super_class, &mut node.body);
         false
     }
 
     fn enter_method_definition(&mut self, node: &mut MethodDefinition) -> bool {
+        self.decorators(&mut node.decorators);
+
         if node.is_static {
             self.string("static");
             self.space();
@@ -848,7 +1275,7 @@ impl Visitor for CodeGenerator<'_> {
     fn enter_new_expr(&mut self, node: &mut ExprNew) -> bool {
         self.string("new");
         self.space();
-        node.callee.traverse(self);
+        self.new_callee(&mut node.callee);
 
         if node.arguments_span.is_some() {
             self.parenthesize('(', false, |s| {
@@ -902,6 +1329,8 @@ impl Visitor for CodeGenerator<'_> {
         self.space();
 
         node.from.traverse(self);
+        node.attributes.traverse(self);
+        self.char(';');
         false
     }
 
@@ -918,6 +1347,7 @@ impl Visitor for CodeGenerator<'_> {
             self.space();
 
             from.traverse(self);
+            node.attributes.traverse(self);
         }
 
         self.char(';');
@@ -976,6 +1406,7 @@ impl Visitor for CodeGenerator<'_> {
         self.space();
 
         node.from.traverse(self);
+        node.attributes.traverse(self);
         self.char(';');
         false
     }
@@ -986,6 +1417,27 @@ impl Visitor for CodeGenerator<'_> {
         false
     }
 
+    fn enter_import_attributes(&mut self, node: &mut ImportAttributes) -> bool {
+        self.space();
+        match node.keyword {
+            ImportAttributesKeyword::With => self.string("with"),
+            ImportAttributesKeyword::Assert => self.string("assert"),
+        }
+        self.space();
+
+        let spaced = !node.attributes.is_empty();
+        self.parenthesize('{', spaced, |s| s.comma_separated(&mut node.attributes));
+        false
+    }
+
+    fn enter_import_attribute(&mut self, node: &mut ImportAttribute) -> bool {
+        node.key.traverse(self);
+        self.char(':');
+        self.space();
+        node.value.traverse(self);
+        false
+    }
+
     fn enter_body(&mut self, node: &mut Body) -> bool {
         self.start_block();
 
@@ -1022,6 +1474,7 @@ impl Visitor for CodeGenerator<'_> {
 
     fn enter_empty_statement(&mut self, _node: &mut StmtEmpty) -> bool {
         self.char(';');
+        self.index.last_empty_stmt.set(Some(self.pos()));
         false
     }
 
@@ -1038,9 +1491,11 @@ impl Visitor for CodeGenerator<'_> {
             self.char('*');
         }
 
-        if node.argument.is_some() {
+        if let Some(argument) = node.argument.as_mut() {
             self.space();
-            node.argument.traverse(self);
+            let argument = self.without_parens(argument);
+            let parenthesize = Self::precedence(argument) == 0;
+            self.operand(argument, parenthesize);
         }
 
         false
@@ -1081,35 +1536,30 @@ impl Visitor for CodeGenerator<'_> {
     }
 
     fn enter_array_binding(&mut self, node: &mut ArrayBinding) -> bool {
-        let spaced = !node.elements.is_empty() || node.rest.is_some();
-        self.parenthesize('[', spaced, |s| {
-            let mut elements = node.elements.iter_mut().peekable();
-            while let Some(element) = elements.next() {
-                element.traverse(s);
-
-                if elements.peek().is_some() || element.is_none() {
-                    s.char(',');
-                }
+        self.array_pattern(&mut node.elements, &mut node.rest);
+        false
+    }
 
-                if elements.peek().is_some() {
-                    s.space();
-                }
-            }
+    fn enter_object_binding(&mut self, node: &mut ObjectBinding) -> bool {
+        let spaced = !node.props.is_empty() || node.rest.is_some();
+        self.parenthesize('{', spaced, |s| {
+            s.comma_separated_with_rest(&mut node.props, &mut node.rest);
+        });
+        false
+    }
 
-            if let Some(rest) = node.rest.as_mut() {
-                if !node.elements.is_empty() {
-                    s.char(',');
-                    s.space();
-                }
+    fn enter_array_assignment_pattern(&mut self, node: &mut ArrayAssignmentPattern) -> bool {
+        self.array_pattern(&mut node.elements, &mut node.rest);
+        false
+    }
 
-                s.string("...");
-                rest.traverse(s);
-            }
-        });
+    fn enter_assignment_element(&mut self, node: &mut AssignmentElement) -> bool {
+        node.target.traverse(self);
+        self.initializer(&mut node.initializer);
         false
     }
 
-    fn enter_object_binding(&mut self, node: &mut ObjectBinding) -> bool {
+    fn enter_object_assignment_pattern(&mut self, node: &mut ObjectAssignmentPattern) -> bool {
         let spaced = !node.props.is_empty() || node.rest.is_some();
         self.parenthesize('{', spaced, |s| {
             s.comma_separated_with_rest(&mut node.props, &mut node.rest);
@@ -1117,6 +1567,21 @@ impl Visitor for CodeGenerator<'_> {
         false
     }
 
+    fn enter_single_name_assignment_prop(&mut self, node: &mut SingleNameAssignmentProp) -> bool {
+        node.ident.traverse(self);
+        self.initializer(&mut node.initializer);
+        false
+    }
+
+    fn enter_named_assignment_prop(&mut self, node: &mut NamedAssignmentProp) -> bool {
+        node.name.traverse(self);
+        self.char(':');
+        self.space();
+        node.value.traverse(self);
+        self.initializer(&mut node.initializer);
+        false
+    }
+
     fn enter_single_name_binding(&mut self, node: &mut SingleNameBinding) -> bool {
         node.ident.traverse(self);
         self.initializer(&mut node.initializer);
@@ -1148,11 +1613,7 @@ impl Visitor for CodeGenerator<'_> {
     fn enter_array_element(&mut self, node: &mut ArrayElement) -> bool {
         match node {
             ArrayElement::Elision => false,
-            ArrayElement::Expr(_) => true,
-            ArrayElement::Spread(_) => {
-                self.string("...");
-                true
-            }
+            ArrayElement::Expr(_) | ArrayElement::Spread(_) => true,
         }
     }
 
@@ -1166,7 +1627,12 @@ impl Visitor for CodeGenerator<'_> {
             Literal::Null => self.string("null"),
             Literal::Boolean(true) => self.string("true"),
             Literal::Boolean(false) => self.string("false"),
-            Literal::Regexp(str) => self.string(str),
+            Literal::Regexp(regexp) => {
+                self.string("/");
+                self.data.push_str(&regexp.pattern);
+                self.data.push('/');
+                self.data.push_str(&regexp.flags);
+            }
             _ => return true,
         }
 
@@ -1174,7 +1640,7 @@ impl Visitor for CodeGenerator<'_> {
     }
 
     fn enter_string_literal(&mut self, node: &mut LitString) -> bool {
-        self.quote(node.delimiter, &node.value);
+        self.quote(node.delimiter, &node.raw);
         false
     }
 
@@ -1188,7 +1654,21 @@ impl Visitor for CodeGenerator<'_> {
     fn enter_array_literal(&mut self, node: &mut LitArray) -> bool {
         let spaced = !node.elements.is_empty();
         self.parenthesize('[', spaced, |s| {
-            s.comma_separated(&mut node.elements);
+            let mut elements = node.elements.iter_mut().peekable();
+            while let Some(element) = elements.next() {
+                element.traverse(s);
+
+                // A trailing comma after the last element is dropped, so holes always need their
+                // own comma, i.e. `[a, ]` is a single element while `[a, , ]` ends with a hole.
+                let is_elision = matches!(element, ArrayElement::Elision);
+                if elements.peek().is_some() || is_elision {
+                    s.char(',');
+                }
+
+                if elements.peek().is_some() {
+                    s.space();
+                }
+            }
         });
         false
     }
@@ -1203,24 +1683,23 @@ impl Visitor for CodeGenerator<'_> {
 
     fn enter_template_part(&mut self, node: &mut TemplatePart) -> bool {
         match node {
-            TemplatePart::String(str) => {
-                self.string(str);
-            }
+            // Template parts are written verbatim, indentation or separating spaces would change
+            // the value of the template.
+            TemplatePart::String(str) => self.data.push_str(str),
             TemplatePart::Expr(expr) => {
-                self.string("${");
+                self.data.push_str("${");
                 expr.traverse(self);
-                self.char('}');
+                self.data.push('}');
             }
         }
 
         false
     }
 
-    fn enter_property_definition(&mut self, node: &mut PropertyDefinition) -> bool {
-        if matches!(node, PropertyDefinition::Spread(_)) {
-            self.string("...");
-        }
-        true
+    fn enter_spread(&mut self, node: &mut Spread) -> bool {
+        self.string("...");
+        node.argument.traverse(self);
+        false
     }
 
     fn enter_named_property(&mut self, node: &mut NamedProperty) -> bool {
@@ -1305,3 +1784,89 @@ impl Visitor for CodeGenerator<'_> {
         false
     }
 }
+
+/// Returns `true` if `expr` starts like a statement when not parenthesized.
+fn is_statement_like(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Function(_)
+            | Expr::Class(_)
+            | Expr::Do(_)
+            | Expr::Literal(ExprLiteral {
+                literal: Literal::Object(_),
+                ..
+            })
+    )
+}
+
+/// Returns `true` if the generated code of `expr` starts with an object literal, i.e. `{a}.b`.
+fn starts_with_object_literal(expr: &Expr) -> bool {
+    starts_with(expr, &|expr| match expr {
+        Expr::Literal(ExprLiteral {
+            literal: Literal::Object(_),
+            ..
+        }) => true,
+        Expr::Assignment(assignment) => matches!(
+            assignment.left.as_ref(),
+            PatternOrExpr::AssignmentPattern(AssignmentPattern::Object(_))
+        ),
+        _ => false,
+    })
+}
+
+/// Returns `true` if the generated code of `expr` starts with the identifier `let`, a statement
+/// starting with `let [` is a lexical declaration.
+fn starts_with_let(expr: &Expr) -> bool {
+    starts_with(
+        expr,
+        &|expr| matches!(expr, Expr::IdentRef(ident) if ident.name == "let"),
+    )
+}
+
+/// Returns `true` if `is_start` is `true` for `expr` or the leftmost operand of `expr`, recursively.
+fn starts_with(expr: &Expr, is_start: &dyn Fn(&Expr) -> bool) -> bool {
+    if is_start(expr) {
+        return true;
+    }
+
+    match expr {
+        Expr::Member(ExprMember {
+            object: MemberObject::Expr(object),
+            ..
+        }) => starts_with(object, is_start),
+        Expr::Call(ExprCall {
+            callee: Callee::Expr(callee),
+            ..
+        }) => starts_with(callee, is_start),
+        Expr::OptionalMember(member) => starts_with(&member.object, is_start),
+        Expr::OptionalCall(call) => starts_with(&call.callee, is_start),
+        Expr::TaggedTemplate(tagged) => starts_with(&tagged.callee, is_start),
+        Expr::Binary(binary) => starts_with(&binary.left, is_start),
+        Expr::Logical(logical) => starts_with(&logical.left, is_start),
+        Expr::Conditional(conditional) => starts_with(&conditional.condition, is_start),
+        Expr::Update(update) if !update.prefix => starts_with(&update.argument, is_start),
+        Expr::Sequence(sequence) => sequence
+            .expr
+            .first()
+            .is_some_and(|first| starts_with(first, is_start)),
+        Expr::Assignment(assignment) => match assignment.left.as_ref() {
+            PatternOrExpr::Expr(left) => starts_with(left, is_start),
+            PatternOrExpr::AssignmentPattern(_) => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns `true` if parentheses around `expr` can be dropped regardless of where it is used.
+/// Number literals are excluded because of member access, i.e. `(1).a`, calls because of
+/// `new (a())` and expressions starting with `let` because of `(let[0]) = 1`.
+fn is_redundant_parens(expr: &Expr) -> bool {
+    match expr {
+        Expr::IdentRef(_) | Expr::Member(_) => !starts_with_let(expr),
+        Expr::This(_) | Expr::Parenthesized(_) | Expr::MetaProperty(_) => true,
+        Expr::Literal(literal) => {
+            !matches!(literal.literal, Literal::Number(_) | Literal::Object(_))
+        }
+        _ => false,
+    }
+}
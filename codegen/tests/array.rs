@@ -0,0 +1,57 @@
+mod utils;
+
+#[test]
+fn empty() {
+    assert_codegen!(input: "[]", minified: "[]");
+}
+
+#[test]
+fn elements() {
+    assert_codegen!(input: "[ a, b ]", minified: "[a,b]");
+}
+
+#[test]
+fn hole_between_elements() {
+    assert_codegen!(input: "[ 1, , 3 ]", minified: "[1,,3]");
+}
+
+#[test]
+fn single_hole() {
+    assert_codegen!(input: "[ , ]", minified: "[,]");
+}
+
+#[test]
+fn only_holes() {
+    assert_codegen!(input: "[ , , ]", minified: "[,,]");
+}
+
+#[test]
+fn trailing_comma() {
+    assert_codegen!(input: "[a,]", output: "[ a ]", minified: "[a]");
+}
+
+#[test]
+fn trailing_hole() {
+    assert_codegen!(input: "[a,,]", output: "[ a, , ]", minified: "[a,,]");
+}
+
+#[test]
+fn leading_hole() {
+    assert_codegen!(input: "[ , a ]", minified: "[,a]");
+}
+
+#[test]
+fn spread_and_trailing_hole() {
+    assert_codegen!(input: "[ ...a, , ]", minified: "[...a,,]");
+}
+
+#[test]
+fn assignment_pattern() {
+    assert_codegen!(input: "[ a, b = 1, ...c ] = d", minified: "[a,b=1,...c]=d");
+    assert_codegen!(input: "[ a, , ...b ] = c", minified: "[a,,...b]=c");
+}
+
+#[test]
+fn binding_pattern_elision_before_rest() {
+    assert_codegen!(module: "var [ a, , ...b ] = c;\n", minified: "var[a,,...b]=c");
+}
@@ -0,0 +1,60 @@
+use fajt_codegen::{generate_code, generate_code_into, GeneratorContext};
+use std::fmt;
+
+const SOURCE: &str = "'use strict';
+var a = 1;
+function f(b, c) { if (b) { return c } return 10 }
+for (let i = 0; i < a; i++) f(i, a)
+class A extends B { m() { return 1 } }
+x = 1
+.5.toString()
+if (a) b(); else c()";
+
+/// Collects everything written to it, chunk by chunk.
+#[derive(Default)]
+struct Chunks(Vec<String>);
+
+impl fmt::Write for Chunks {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.push(s.to_owned());
+        Ok(())
+    }
+}
+
+fn contexts() -> [GeneratorContext; 2] {
+    let mut minified = GeneratorContext::new();
+    minified.minified = true;
+    [GeneratorContext::new(), minified]
+}
+
+#[test]
+fn writes_same_output_as_generate_code() {
+    for ctx in contexts() {
+        let mut program = fajt_parser::parse_program(SOURCE).unwrap();
+        let expected = generate_code(&mut program.clone(), ctx.clone());
+
+        let mut string = String::new();
+        generate_code_into(&mut program.clone(), ctx.clone(), &mut string).unwrap();
+        assert_eq!(string, expected);
+
+        let mut chunks = Chunks::default();
+        generate_code_into(&mut program, ctx, &mut chunks).unwrap();
+        assert_eq!(chunks.0.concat(), expected);
+        assert!(chunks.0.len() > 1, "Output is not written in chunks");
+    }
+}
+
+struct FailingWriter;
+
+impl fmt::Write for FailingWriter {
+    fn write_str(&mut self, _s: &str) -> fmt::Result {
+        Err(fmt::Error)
+    }
+}
+
+#[test]
+fn write_error_is_returned() {
+    let mut program = fajt_parser::parse_program(SOURCE).unwrap();
+    let result = generate_code_into(&mut program, GeneratorContext::new(), &mut FailingWriter);
+    assert_eq!(result, Err(fmt::Error));
+}
@@ -0,0 +1,21 @@
+mod utils;
+
+#[test]
+fn for_sequence_init_and_update() {
+    assert_codegen!(
+        module: "for (i = 0, j = 10; i < j; i++, j--) ;\n",
+        minified: "for(i=0,j=10;i<j;i++,j--);"
+    );
+    assert_codegen!(
+        module: "for (var i = 0, j = 10; i < j; i++, j--) ;\n",
+        minified: "for(var i=0,j=10;i<j;i++,j--);"
+    );
+}
+
+#[test]
+fn for_parenthesized_in_init() {
+    assert_codegen!(
+        module: "for (a = (b in c), d;;) ;\n",
+        minified: "for(a=(b in c),d;;);"
+    );
+}
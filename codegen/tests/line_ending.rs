@@ -0,0 +1,61 @@
+use fajt_ast::{Program, SourceType};
+use fajt_codegen::{generate_code, generate_code_into, GeneratorContext, LineEnding};
+
+fn generate(input: &str, ctx: GeneratorContext) -> String {
+    let mut program =
+        fajt_parser::parse::<Program>(input, SourceType::Script).expect("Failed to parse input.");
+    generate_code(&mut program, ctx)
+}
+
+fn context(line_ending: LineEnding) -> GeneratorContext {
+    let mut ctx = GeneratorContext::new();
+    ctx.line_ending = line_ending;
+    ctx
+}
+
+#[test]
+fn crlf_between_statements() {
+    assert_eq!(
+        generate("a = 1; b()", context(LineEnding::Crlf)),
+        "a = 1;\r\nb();\r\n"
+    );
+}
+
+#[test]
+fn crlf_in_blocks() {
+    assert_eq!(
+        generate("function f() { a; } {}", context(LineEnding::Crlf)),
+        "function f() {\r\n    a;\r\n}\r\n{}\r\n"
+    );
+}
+
+#[test]
+fn crlf_without_final_newline() {
+    let mut ctx = context(LineEnding::Crlf);
+    ctx.final_newline = false;
+    assert_eq!(generate("a; b;", ctx), "a;\r\nb;");
+}
+
+#[test]
+fn minified_has_no_line_endings() {
+    let mut ctx = context(LineEnding::Crlf);
+    ctx.minified = true;
+    assert_eq!(generate("a; b;", ctx), "a;b");
+}
+
+#[test]
+fn lf_is_default() {
+    assert_eq!(GeneratorContext::new().line_ending, LineEnding::Lf);
+    assert_eq!(generate("a; b;", GeneratorContext::new()), "a;\nb;\n");
+}
+
+#[test]
+fn crlf_is_removed_after_flush() {
+    let mut program = fajt_parser::parse_program("a; b;").unwrap();
+    let mut ctx = context(LineEnding::Crlf);
+    ctx.final_newline = false;
+
+    let mut out = String::new();
+    generate_code_into(&mut program, ctx, &mut out).unwrap();
+    assert_eq!(out, "a;\r\nb;");
+}
@@ -0,0 +1,70 @@
+mod utils;
+
+#[test]
+fn keyword_before_identifier() {
+    assert_codegen!(input: "typeof y", minified: "typeof y");
+    assert_codegen!(input: "a in b", minified: "a in b");
+    assert_codegen!(input: "a instanceof $b", minified: "a instanceof $b");
+    assert_codegen!(input: "void _a", minified: "void _a");
+}
+
+#[test]
+fn keyword_before_punctuator() {
+    assert_codegen!(input: "typeof [ a ]", output: "typeof[ a ]", minified: "typeof[a]");
+    assert_codegen!(input: "a in [ b ]", minified: "a in[b]");
+}
+
+#[test]
+fn return_argument() {
+    assert_codegen!(input: "function f() {\n    return x;\n}", output: "function f() {\n    return x;\n}", minified: "function f(){return x}");
+}
+
+#[test]
+fn plus_and_minus_operands() {
+    assert_codegen!(input: "a + +b", minified: "a+ +b");
+    assert_codegen!(input: "a - -b", minified: "a- -b");
+    assert_codegen!(input: "a + ++b", minified: "a+ ++b");
+    assert_codegen!(input: "a++ + b", minified: "a++ +b");
+    assert_codegen!(input: "a + -b", minified: "a+-b");
+}
+
+#[test]
+fn division_before_regexp() {
+    assert_codegen!(input: "a / /b/", minified: "a/ /b/");
+}
+
+#[test]
+fn member_of_integer_literal() {
+    assert_codegen!(input: "1 .toString()", minified: "1 .toString()");
+    assert_codegen!(input: "1.5.toString()", minified: "1.5.toString()");
+    assert_codegen!(input: "a1.toString()", minified: "a1.toString()");
+}
+
+#[test]
+fn regexp_literal() {
+    assert_codegen!(input: "a = /[/]b\\//gi", minified: "a=/[/]b\\//gi");
+    assert_codegen!(input: "a in /b/", minified: "a in/b/");
+}
+
+#[test]
+fn number_member_access() {
+    assert_codegen!(input: "1 .x", minified: "1 .x");
+    assert_codegen!(input: "1.5.toString", minified: "1.5.toString");
+    assert_codegen!(input: "(1).x", minified: "(1).x");
+    assert_codegen!(input: "1..x", minified: "1..x");
+}
+
+#[test]
+fn member_of_integer_literal_with_separators() {
+    assert_codegen!(input: "1_0 .a", minified: "1_0 .a");
+    assert_codegen!(input: "1_0.5.a", minified: "1_0.5.a");
+    assert_codegen!(input: "_1.a", minified: "_1.a");
+}
+
+#[test]
+fn html_like_comments() {
+    assert_codegen!(input: "a < !--b", minified: "a< !--b");
+    assert_codegen!(input: "a-- > b", minified: "a-- >b");
+    assert_codegen!(input: "a < !b", minified: "a<!b");
+    assert_codegen!(input: "a - -b", minified: "a- -b");
+}
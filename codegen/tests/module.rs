@@ -0,0 +1,45 @@
+mod utils;
+
+#[test]
+fn import() {
+    assert_codegen!(module: "import 'm';\n", minified: "import'm'");
+    assert_codegen!(module: "import a from 'm';\n", minified: "import a from'm'");
+    assert_codegen!(module: "import * as ns from 'm';\n", minified: "import*as ns from'm'");
+    assert_codegen!(module: "import a, * as ns from 'm';\n", minified: "import a,*as ns from'm'");
+    assert_codegen!(module: "import {} from 'm';\n", minified: "import{}from'm'");
+}
+
+#[test]
+fn import_named_alias() {
+    assert_codegen!(module: "import a, { b as c } from 'm';\n", minified: "import a,{b as c}from'm'");
+    assert_codegen!(module: "import { 'b' as c, d } from 'm';\n", minified: "import{'b'as c,d}from'm'");
+}
+
+#[test]
+fn export_named_alias() {
+    assert_codegen!(module: "export { a as b, c };\n", minified: "export{a as b,c}");
+    assert_codegen!(module: "export { a as b } from 'm';\n", minified: "export{a as b}from'm'");
+    assert_codegen!(module: "export { default as a } from 'm';\n", minified: "export{default as a}from'm'");
+}
+
+#[test]
+fn export_namespace() {
+    assert_codegen!(module: "export * from 'm';\n", minified: "export*from'm'");
+    assert_codegen!(module: "export * as ns from 'm';\n", minified: "export*as ns from'm'");
+    assert_codegen!(module: "export * as ns from 'm';\nexport * from 'n';\n", minified: "export*as ns from'm';export*from'n'");
+}
+
+#[test]
+fn export_declaration() {
+    assert_codegen!(module: "export var a = b;\n", minified: "export var a=b");
+    assert_codegen!(module: "export const a = b;\n", minified: "export const a=b");
+    assert_codegen!(module: "export function f() {}\n", minified: "export function f(){}");
+    assert_codegen!(module: "export class A {}\n", minified: "export class A{}");
+}
+
+#[test]
+fn export_default() {
+    assert_codegen!(module: "export default a;\n", minified: "export default a");
+    assert_codegen!(module: "export default function () {}\n", minified: "export default function(){}");
+    assert_codegen!(module: "export default class {}\n", minified: "export default class{}");
+}
@@ -0,0 +1,43 @@
+mod utils;
+
+#[test]
+fn number_keys_keep_raw_form() {
+    assert_codegen!(input: "{ 0x10: a }", minified: "{0x10:a}");
+    assert_codegen!(input: "{ 0.5: a }", minified: "{0.5:a}");
+    assert_codegen!(input: "{ 1e3: a }", minified: "{1e3:a}");
+    assert_codegen!(input: "{ 0b101: a }", minified: "{0b101:a}");
+}
+
+#[test]
+fn string_keys_keep_delimiter() {
+    assert_codegen!(input: "{ 'a': b }", minified: "{'a':b}");
+    assert_codegen!(input: "{ \"a-b\": c }", minified: "{\"a-b\":c}");
+}
+
+#[test]
+fn method_number_keys() {
+    assert_codegen!(input: "{ get 0x10() {} }", minified: "{get 0x10(){}}");
+    assert_codegen!(input: "{ set 0.5(a) {} }", minified: "{set 0.5(a){}}");
+    assert_codegen!(input: "{ 0o17() {} }", minified: "{0o17(){}}");
+}
+
+#[test]
+fn spread() {
+    assert_codegen!(input: "{ ...base, x: 1 }", minified: "{...base,x:1}");
+    assert_codegen!(input: "{ a, ...b, ...c }", minified: "{a,...b,...c}");
+}
+
+#[test]
+fn rest_binding() {
+    assert_codegen!(module: "const { a, ...rest } = o;\n", minified: "const{a,...rest}=o");
+    assert_codegen!(module: "({ a, ...rest } = o);\n", minified: "({a,...rest}=o)");
+}
+
+#[test]
+fn assignment_pattern() {
+    assert_codegen!(
+        module: "({ a, b: c = 1, ...d.e } = o);\n",
+        minified: "({a,b:c=1,...d.e}=o)"
+    );
+    assert_codegen!(module: "({ a: [ b, , ...c ] } = o);\n", minified: "({a:[b,,...c]}=o)");
+}
@@ -0,0 +1,245 @@
+mod utils;
+
+use fajt_ast::{Expr, SourceType};
+use fajt_codegen::{generate_code, GeneratorContext};
+use utils::{strip_parens, strip_spans};
+
+/// Parses `input` and removes all parenthesized expressions from the AST, then asserts that the
+/// generated code is `output`. The output must parse back to the same (parentheses free) AST.
+macro_rules! assert_parens {
+    (input: $input:expr, output: $output:expr, minified: $minified:expr $(,)?) => {{
+        let mut expr = parse_without_parens($input);
+        let output = generate_code(&mut expr, GeneratorContext::new());
+        assert_eq!(output, $output, "Formatted output mismatch.");
+
+        let mut ctx = GeneratorContext::new();
+        ctx.minified = true;
+        let output_min = generate_code(&mut expr, ctx);
+        assert_eq!(output_min, $minified, "Minified output mismatch.");
+
+        for output in [output, output_min] {
+            assert_eq!(
+                strip_spans(serde_json::to_value(parse_without_parens(&output)).unwrap()),
+                strip_spans(serde_json::to_value(&expr).unwrap()),
+                "Generated code `{}` does not round-trip.",
+                output
+            );
+        }
+    }};
+}
+
+fn parse_without_parens(input: &str) -> Expr {
+    let expr =
+        fajt_parser::parse::<Expr>(input, SourceType::Script).expect("Failed to parse input.");
+    serde_json::from_value(strip_parens(serde_json::to_value(expr).unwrap())).unwrap()
+}
+
+#[test]
+fn source_parens_are_kept() {
+    assert_codegen!(input: "(a + b) * c", minified: "(a+b)*c");
+    assert_codegen!(input: "a + (b * c)", minified: "a+(b*c)");
+}
+
+#[test]
+fn lower_precedence_operand() {
+    assert_parens!(input: "(a + b) * c", output: "(a + b) * c", minified: "(a+b)*c");
+    assert_parens!(input: "a * (b + c)", output: "a * (b + c)", minified: "a*(b+c)");
+    assert_parens!(input: "(a || b) && c", output: "(a || b) && c", minified: "(a||b)&&c");
+    assert_parens!(input: "(a | b) == c", output: "(a | b) == c", minified: "(a|b)==c");
+}
+
+#[test]
+fn minimal_parens() {
+    assert_parens!(input: "a + (b * c)", output: "a + b * c", minified: "a+b*c");
+    assert_parens!(input: "(a * b) + c", output: "a * b + c", minified: "a*b+c");
+    assert_parens!(input: "((a + b) + c)", output: "a + b + c", minified: "a+b+c");
+    assert_parens!(input: "(a && b) || c", output: "a && b || c", minified: "a&&b||c");
+    assert_parens!(input: "a ** (b ** c)", output: "a ** b ** c", minified: "a**b**c");
+}
+
+#[test]
+fn equal_precedence_against_associativity() {
+    assert_parens!(input: "a - (b - c)", output: "a - (b - c)", minified: "a-(b-c)");
+    assert_parens!(input: "a + (b + c)", output: "a + (b + c)", minified: "a+(b+c)");
+    assert_parens!(input: "(a ** b) ** c", output: "(a ** b) ** c", minified: "(a**b)**c");
+}
+
+#[test]
+fn unary_base_of_exponent() {
+    assert_parens!(input: "(-a) ** b", output: "(-a) ** b", minified: "(-a)**b");
+    assert_parens!(input: "a ** -b", output: "a ** -b", minified: "a**-b");
+}
+
+#[test]
+fn coalesce_mixed_with_logical() {
+    assert_parens!(input: "(a || b) ?? c", output: "(a || b) ?? c", minified: "(a||b)??c");
+    assert_parens!(input: "a ?? (b && c)", output: "a ?? (b && c)", minified: "a??(b&&c)");
+}
+
+#[test]
+fn unary_argument() {
+    assert_parens!(input: "!(a && b)", output: "!(a && b)", minified: "!(a&&b)");
+    assert_parens!(input: "typeof (a + b)", output: "typeof(a + b)", minified: "typeof(a+b)");
+}
+
+#[test]
+fn assignment_and_conditional_operands() {
+    assert_parens!(input: "(a ? b : c) + d", output: "(a ? b : c) + d", minified: "(a?b:c)+d");
+    assert_parens!(input: "a + (b = c)", output: "a + (b = c)", minified: "a+(b=c)");
+}
+
+/// Generates `input` with parentheses dropped where they are not required.
+fn generate_without_preserved_parens(input: &str, minified: bool) -> String {
+    let mut expr =
+        fajt_parser::parse::<Expr>(input, SourceType::Script).expect("Failed to parse input.");
+    let mut ctx = GeneratorContext::new();
+    ctx.minified = minified;
+    ctx.preserve_parens = false;
+    generate_code(&mut expr, ctx)
+}
+
+#[test]
+fn preserve_parens() {
+    assert_codegen!(input: "(a + b) * c", minified: "(a+b)*c");
+    assert_codegen!(input: "(a)", minified: "(a)");
+}
+
+#[test]
+fn drop_redundant_parens() {
+    assert_eq!(
+        generate_without_preserved_parens("(a + b) * c", false),
+        "(a + b) * c"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("(a + b) * c", true),
+        "(a+b)*c"
+    );
+    assert_eq!(generate_without_preserved_parens("(a)", false), "a");
+    assert_eq!(generate_without_preserved_parens("(a)", true), "a");
+    assert_eq!(
+        generate_without_preserved_parens("((a + b)) * c", true),
+        "(a+b)*c"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("a + (b * c)", true),
+        "a+b*c"
+    );
+    assert_eq!(generate_without_preserved_parens("!((a))", true), "!a");
+}
+
+#[test]
+fn drop_redundant_parens_keeps_required() {
+    assert_eq!(generate_without_preserved_parens("(1).a", true), "(1).a");
+    assert_eq!(
+        generate_without_preserved_parens("new (a())", true),
+        "new(a())"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("({}) + a", true),
+        "({})+a"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("(a, b) + c", true),
+        "(a,b)+c"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("new (a().b)", true),
+        "new(a().b)"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("new (a().b).c", true),
+        "new(a().b.c)"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("new (new a)()", true),
+        "new(new a)()"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("(new a).b", true),
+        "(new a).b"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("(new a)()", true),
+        "(new a)()"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("(a?.b).c", true),
+        "(a?.b).c"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("(a?.b)()", true),
+        "(a?.b)()"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("(let[0]) = 1", true),
+        "(let[0])=1"
+    );
+    assert_eq!(
+        generate_without_preserved_parens("(let)[0] = 1", true),
+        "(let)[0]=1"
+    );
+}
+
+#[test]
+fn await_and_yield() {
+    assert_codegen!(
+        input: "async function f() {\n    await a.b;\n}",
+        minified: "async function f(){await a.b}",
+    );
+    assert_codegen!(
+        input: "async function f() {\n    (await a).b;\n}",
+        minified: "async function f(){(await a).b}",
+    );
+    assert_codegen!(
+        input: "function* f() {\n    yield* gen();\n}",
+        minified: "function*f(){yield*gen()}",
+    );
+    assert_codegen!(
+        input: "function* f() {\n    yield a;\n    yield;\n}",
+        minified: "function*f(){yield a;yield}",
+    );
+}
+
+#[test]
+fn await_and_yield_operands() {
+    assert_parens!(
+        input: "async function f() {\n    (await a).b;\n}",
+        output: "async function f() {\n    (await a).b;\n}",
+        minified: "async function f(){(await a).b}",
+    );
+    assert_parens!(
+        input: "async function f() {\n    (await a)();\n}",
+        output: "async function f() {\n    (await a)();\n}",
+        minified: "async function f(){(await a)()}",
+    );
+    assert_parens!(
+        input: "async function f() {\n    await (a + b);\n}",
+        output: "async function f() {\n    await (a + b);\n}",
+        minified: "async function f(){await(a+b)}",
+    );
+    assert_parens!(
+        input: "function* f() {\n    yield (a, b);\n}",
+        output: "function* f() {\n    yield (a, b);\n}",
+        minified: "function*f(){yield(a,b)}",
+    );
+    assert_parens!(
+        input: "function* f() {\n    (yield a) + b;\n}",
+        output: "function* f() {\n    (yield a) + b;\n}",
+        minified: "function*f(){(yield a)+b}",
+    );
+    assert_parens!(input: "(a + b).c", output: "(a + b).c", minified: "(a+b).c");
+}
+
+#[test]
+fn number_member_object() {
+    assert_parens!(input: "(1).x", output: "1 .x", minified: "1 .x");
+    assert_parens!(input: "(1.5).x", output: "1.5.x", minified: "1.5.x");
+}
+
+#[test]
+fn arrow_function_object_body() {
+    assert_codegen!(input: "a => ({ b: 1 })", minified: "a=>({b:1})");
+    assert_parens!(input: "a => ({ b: 1 })", output: "a => ({ b: 1 })", minified: "a=>({b:1})");
+    assert_parens!(input: "a => ({ b: 1 }).b", output: "a => ({ b: 1 }.b)", minified: "a=>({b:1}.b)");
+    assert_parens!(input: "a => ({ b } = c)", output: "a => ({ b } = c)", minified: "a=>({b}=c)");
+}
@@ -0,0 +1,72 @@
+mod utils;
+
+use fajt_ast::{Program, SourceType};
+use fajt_codegen::{generate_code, GeneratorContext, SemicolonStyle};
+
+fn generate(input: &str, ctx: GeneratorContext) -> String {
+    let mut program =
+        fajt_parser::parse::<Program>(input, SourceType::Script).expect("Failed to parse input.");
+    generate_code(&mut program, ctx)
+}
+
+fn context(minified: bool, final_newline: bool, semicolons: SemicolonStyle) -> GeneratorContext {
+    let mut ctx = GeneratorContext::new();
+    ctx.minified = minified;
+    ctx.final_newline = final_newline;
+    ctx.semicolons = Some(semicolons);
+    ctx
+}
+
+#[test]
+fn formatted() {
+    let input = "a = 1; b()";
+    let generate =
+        |final_newline, semicolons| generate(input, context(false, final_newline, semicolons));
+
+    assert_eq!(generate(true, SemicolonStyle::Always), "a = 1;\nb();\n");
+    assert_eq!(generate(true, SemicolonStyle::AsNeeded), "a = 1;\nb()\n");
+    assert_eq!(generate(false, SemicolonStyle::Always), "a = 1;\nb();");
+    assert_eq!(generate(false, SemicolonStyle::AsNeeded), "a = 1;\nb()");
+}
+
+#[test]
+fn minified() {
+    let input = "a = 1; b()";
+    let generate =
+        |final_newline, semicolons| generate(input, context(true, final_newline, semicolons));
+
+    // Minified output never ends with a new line.
+    assert_eq!(generate(true, SemicolonStyle::Always), "a=1;b();");
+    assert_eq!(generate(true, SemicolonStyle::AsNeeded), "a=1;b()");
+    assert_eq!(generate(false, SemicolonStyle::Always), "a=1;b();");
+    assert_eq!(generate(false, SemicolonStyle::AsNeeded), "a=1;b()");
+}
+
+#[test]
+fn blocks() {
+    let input = "function f() { a; b }";
+    assert_eq!(
+        generate(input, context(false, true, SemicolonStyle::AsNeeded)),
+        "function f() {\n    a;\n    b\n}\n"
+    );
+    assert_eq!(
+        generate(input, context(true, false, SemicolonStyle::Always)),
+        "function f(){a;b;}"
+    );
+}
+
+#[test]
+fn default_style() {
+    assert_eq!(generate("a; b", GeneratorContext::new()), "a;\nb;\n");
+
+    let mut ctx = GeneratorContext::new();
+    ctx.minified = true;
+    assert_eq!(generate("a; b", ctx), "a;b");
+}
+
+#[test]
+fn empty_statement_is_kept() {
+    assert_codegen!(module: "if (a)\n    ;\n", minified: "if(a);");
+    assert_codegen!(module: "{\n    if (a)\n        ;\n}\n", minified: "{if(a);}");
+    assert_codegen!(module: "a;\n;\n", minified: "a;;");
+}
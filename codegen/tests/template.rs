@@ -0,0 +1,32 @@
+mod utils;
+
+#[test]
+fn template_with_substitutions() {
+    assert_codegen!(input: "`a${ b }c${d + e}f`", output: "`a${b}c${d + e}f`", minified: "`a${b}c${d+e}f`");
+}
+
+#[test]
+fn template_without_substitutions() {
+    assert_codegen!(input: "`abc`", minified: "`abc`");
+    assert_codegen!(input: "``", minified: "``");
+}
+
+#[test]
+fn template_with_escapes() {
+    assert_codegen!(input: r"`a\`b\${c}${d}\\`", minified: r"`a\`b\${c}${d}\\`");
+}
+
+#[test]
+fn template_with_new_lines() {
+    assert_codegen!(
+        module: "function f() {\n    return `a\n${b}\n  c`;\n}\n",
+        minified: "function f(){return`a\n${b}\n  c`}",
+    );
+}
+
+#[test]
+fn tagged_template() {
+    assert_codegen!(input: "tag`a${b}c`", minified: "tag`a${b}c`");
+    assert_codegen!(input: "a.b`c`", minified: "a.b`c`");
+    assert_codegen!(input: "tag``", minified: "tag``");
+}
@@ -0,0 +1,104 @@
+/// Test macro for parsing an expression and generating code from it.
+///
+/// Assert output:
+/// ```
+/// assert_codegen!(
+///     // Expression to parse.
+///     input: "[ a, , b ]",
+///
+///     // Formatted output, defaults to the input if omitted.
+///     output: "[ a, , b ]",
+///
+///     // Minified output.
+///     minified: "[a,,b]",
+/// )
+/// ```
+///
+/// Use `module:` instead of `input:` to parse a whole program as a module.
+#[macro_export]
+macro_rules! assert_codegen {
+    (input: $input:expr, $(output: $output:expr,)? minified: $minified:expr $(,)?) => {
+        $crate::assert_codegen!(
+            @assert fajt_ast::Expr, fajt_ast::SourceType::Script,
+            input: $input, $(output: $output,)? minified: $minified
+        )
+    };
+    (module: $input:expr, $(output: $output:expr,)? minified: $minified:expr $(,)?) => {
+        $crate::assert_codegen!(
+            @assert fajt_ast::Program, fajt_ast::SourceType::Module,
+            input: $input, $(output: $output,)? minified: $minified
+        )
+    };
+    (
+        @assert $node:ty, $source_type:expr,
+        input: $input:expr, $(output: $output:expr,)? minified: $minified:expr
+    ) => {{
+        let expr = fajt_parser::parse::<$node>($input, $source_type)
+            .expect("Failed to parse input.");
+
+        #[allow(unused_variables)]
+        let expected = $input;
+        $(
+            let expected = $output;
+        )?
+
+        let mut formatted = expr.clone();
+        let output = fajt_codegen::generate_code(
+            &mut formatted,
+            fajt_codegen::GeneratorContext::new(),
+        );
+        assert_eq!(output, expected, "Formatted output mismatch.");
+
+        let mut minified = expr.clone();
+        let mut ctx = fajt_codegen::GeneratorContext::new();
+        ctx.minified = true;
+        let output_min = fajt_codegen::generate_code(&mut minified, ctx);
+        assert_eq!(output_min, $minified, "Minified output mismatch.");
+
+        // The generated code must parse back to the same AST, ignoring spans.
+        for output in [output, output_min] {
+            let reparsed =
+                fajt_parser::parse::<$node>(&output, $source_type)
+                    .expect("Failed to parse generated code.");
+            assert_eq!(
+                $crate::utils::strip_spans(serde_json::to_value(&reparsed).unwrap()),
+                $crate::utils::strip_spans(serde_json::to_value(&expr).unwrap()),
+                "Generated code `{}` does not round-trip.",
+                output
+            );
+        }
+    }};
+}
+
+/// Removes all span fields so AST produced from differently formatted code can be compared.
+pub fn strip_spans(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .filter(|(key, _)| !key.ends_with("span"))
+            .map(|(key, value)| (key, strip_spans(value)))
+            .collect(),
+        serde_json::Value::Array(values) => values.into_iter().map(strip_spans).collect(),
+        value => value,
+    }
+}
+
+/// Removes all parenthesized expressions, leaving only their inner expression. This gives an AST
+/// where any parentheses in the generated code must be added by the code generator.
+#[allow(dead_code)]
+pub fn strip_parens(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(mut map) => {
+            if let Some(serde_json::Value::Object(mut parenthesized)) = map.remove("Parenthesized")
+            {
+                return strip_parens(parenthesized.remove("expression").unwrap());
+            }
+
+            map.into_iter()
+                .map(|(key, value)| (key, strip_parens(value)))
+                .collect()
+        }
+        serde_json::Value::Array(values) => values.into_iter().map(strip_parens).collect(),
+        value => value,
+    }
+}
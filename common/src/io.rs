@@ -90,6 +90,17 @@ where
         })
     }
 
+    /// Replaces the inner reader, the reader is reset to the same state as after `with_offset`
+    /// with the current offset. Returns error if inner reader returns error when reading first 2
+    /// items.
+    pub fn reset(&mut self, mut inner: I) -> Result<(), I::Error> {
+        self.current = inner.next();
+        self.next = inner.next();
+        self.inner = inner;
+        self.position = self.offset;
+        Ok(())
+    }
+
     /// Current position of reader, i.e. end position of last consumed item.
     pub fn position(&self) -> usize {
         self.position
@@ -31,12 +31,63 @@ where
     }
 }
 
+/// Reads the characters of a string together with their end positions.
+///
+/// Strings with only ASCII characters are read byte by byte, which is faster than decoding them
+/// as UTF-8 with `CharIndices`. The result is the same either way.
+#[derive(Debug, Clone)]
+pub enum StrReader<'a> {
+    Ascii { bytes: &'a [u8], position: usize },
+    Unicode(CharIndices<'a>),
+}
+
+impl<'a> StrReader<'a> {
+    /// Returns a reader of `data`, using the ASCII fast path if `data` only contains ASCII.
+    pub fn new(data: &'a str) -> Self {
+        Self::with_known_ascii(data, data.is_ascii())
+    }
+
+    /// Same as `new` but without checking `data`, used when `data` is known to be ASCII, e.g. a
+    /// part of a string that has already been checked. Not checked in debug builds either, since
+    /// the lexer creates a reader of the rest of the source on every seek.
+    pub fn with_known_ascii(data: &'a str, is_ascii: bool) -> Self {
+        if is_ascii {
+            StrReader::Ascii {
+                bytes: data.as_bytes(),
+                position: 0,
+            }
+        } else {
+            StrReader::Unicode(data.char_indices())
+        }
+    }
+
+    /// Returns `true` if the ASCII fast path is used.
+    pub fn is_ascii(&self) -> bool {
+        matches!(self, StrReader::Ascii { .. })
+    }
+}
+
+impl PeekRead<char> for StrReader<'_> {
+    type Error = Error;
+
+    fn next(&mut self) -> Result<(usize, char), Self::Error> {
+        match self {
+            StrReader::Ascii { bytes, position } => {
+                let byte = *bytes.get(*position).ok_or(EndOfStream)?;
+                *position += 1;
+                Ok((*position, char::from(byte)))
+            }
+            StrReader::Unicode(chars) => PeekRead::next(chars),
+        }
+    }
+}
+
 impl PeekRead<char> for CharIndices<'_> {
     type Error = Error;
 
     fn next(&mut self) -> Result<(usize, char), Self::Error> {
         Iterator::next(self)
-            .map(|(pos, c)| (pos + c.len_utf16(), c))
+            .map(|(pos, c)| (pos + c.len_utf8(), c))
             .ok_or(EndOfStream)
     }
 }
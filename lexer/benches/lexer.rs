@@ -0,0 +1,97 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use fajt_lexer::Lexer;
+
+const CHUNK: &str = r#"
+function fibonacci(n) {
+    // Iterative version to avoid deep recursion.
+    let [a, b] = [0, 1];
+    for (let i = 0; i < n; i++) {
+        [a, b] = [b, a + b];
+    }
+    return a;
+}
+
+class Point {
+    constructor(x, y) {
+        this.x = x;
+        this.y = y;
+    }
+
+    get length() {
+        return Math.sqrt(this.x ** 2 + this.y ** 2);
+    }
+}
+
+const points = [1, 2, 3].map((n) => new Point(n, fibonacci(n) * 0.5));
+const label = "points: " + points.length;
+if (label !== "points" && points.length > 0) {
+    console.log('done', { label, points });
+}
+"#;
+
+/// Synthetic source of about 1 MB, `unicode` adds a non-ASCII character to every chunk.
+fn synthetic_source(unicode: bool) -> String {
+    let chunk = if unicode {
+        format!("{CHUNK}const \u{e5}\u{e4}\u{f6} = 1;\n")
+    } else {
+        format!("{CHUNK}const aao = 1;\n")
+    };
+    chunk.repeat(1_000_000 / chunk.len())
+}
+
+/// Synthetic source of about 1 MB with only identifiers and keywords.
+fn identifier_source() -> String {
+    let words = [
+        "value",
+        "let",
+        "accumulator",
+        "if",
+        "index",
+        "instanceof",
+        "result",
+        "function",
+        "x",
+        "await",
+        "awaiting",
+        "implements",
+        "implementation",
+        "returnValue",
+        "this",
+        "yield_",
+    ];
+    let chunk = words.join(" ") + "\n";
+    chunk.repeat(1_000_000 / chunk.len())
+}
+
+fn lex_all(source: &str) -> usize {
+    let mut lexer = Lexer::new(source).unwrap();
+    lexer.read_all().unwrap().len()
+}
+
+fn lexer_benchmark(c: &mut Criterion) {
+    let ascii = synthetic_source(false);
+    let unicode = synthetic_source(true);
+
+    // Make sure the benchmarks measure what they claim to.
+    assert!(Lexer::new(&ascii).unwrap().is_ascii());
+    assert!(!Lexer::new(&unicode).unwrap().is_ascii());
+
+    let mut group = c.benchmark_group("lexer");
+    group.throughput(Throughput::Bytes(ascii.len() as u64));
+    group.bench_function("ascii", |b| b.iter(|| lex_all(black_box(&ascii))));
+    group.throughput(Throughput::Bytes(unicode.len() as u64));
+    group.bench_function("unicode", |b| b.iter(|| lex_all(black_box(&unicode))));
+    group.finish();
+}
+
+fn identifier_benchmark(c: &mut Criterion) {
+    let source = identifier_source();
+
+    let mut group = c.benchmark_group("lexer");
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    group.bench_function("identifiers", |b| b.iter(|| lex_all(black_box(&source))));
+    group.finish();
+}
+
+criterion_group!(benches, lexer_benchmark, identifier_benchmark);
+criterion_main!(benches);
@@ -17,11 +17,11 @@ use crate::error::Error;
 use crate::error::ErrorKind::{EndOfStream, InvalidOrUnexpectedToken};
 use crate::token::Token;
 use crate::token::TokenValue;
-use fajt_ast::{LitTemplate, Literal, Span, TemplatePart};
+use fajt_ast::{Atom, Interner, LitTemplate, Literal, Span, TemplatePart};
+use fajt_common::io::char_reader::StrReader;
 use fajt_common::io::{PeekRead, PeekReader, ReReadWithState};
 use std::io::{Seek, SeekFrom};
 use std::mem;
-use std::str::CharIndices;
 
 /// Consume code points from lexer to produce data.
 ///
@@ -65,28 +65,125 @@ macro_rules! produce {
 
 type Result<T> = std::result::Result<T, Error>;
 
+/// Returns `source` without a leading UTF-8 byte order mark. The lexer skips a byte order mark at
+/// the start of the source, spans are still relative to the whole source.
+///
+/// ```
+/// assert_eq!(fajt_lexer::strip_bom("\u{feff}var x;"), "var x;");
+/// assert_eq!(fajt_lexer::strip_bom("var x;"), "var x;");
+/// ```
+pub fn strip_bom(source: &str) -> &str {
+    source.strip_prefix('\u{feff}').unwrap_or(source)
+}
+
 pub struct Lexer<'a> {
     data: &'a str,
     state: LexerState,
-    reader: PeekReader<char, CharIndices<'a>>,
+    reader: PeekReader<char, StrReader<'a>>,
+    is_ascii: bool,
     first_on_line: bool,
+    legacy_octal: bool,
+    escaped: bool,
+    word_buffer: String,
+    interner: Option<Interner>,
+    eof_token: bool,
+    eof_read: bool,
 }
 
 impl<'a> Lexer<'a> {
+    /// Creates a lexer reading `data`, a leading byte order mark is skipped, see `strip_bom`.
     pub fn new(data: &'a str) -> Result<Self> {
-        let reader = PeekReader::new(data.char_indices())?;
+        let start = data.len() - strip_bom(data).len();
+        let chars = StrReader::new(&data[start..]);
+        let is_ascii = chars.is_ascii();
+        let reader = PeekReader::with_offset(chars, start)?;
         Ok(Lexer {
             data,
             state: LexerState::default(),
             reader,
+            is_ascii,
             first_on_line: true,
+            legacy_octal: false,
+            escaped: false,
+            word_buffer: String::new(),
+            interner: None,
+            eof_token: false,
+            eof_read: false,
         })
     }
 
+    /// Returns `true` if the source only contains ASCII, which is read with a faster reader.
+    pub fn is_ascii(&self) -> bool {
+        self.is_ascii
+    }
+
+    /// Enables or disables interning of identifiers. With interning, identifiers with the same
+    /// name share one allocation, which reduces allocations for large sources at the cost of a
+    /// lookup per identifier.
+    pub fn set_interning(&mut self, enabled: bool) {
+        if !enabled {
+            self.interner = None;
+        } else if self.interner.is_none() {
+            self.interner = Some(Interner::new());
+        }
+    }
+
+    /// Enables or disables reading a `TokenValue::Eof` token at the end of the source. When enabled,
+    /// the end of the source is read as one zero width `Eof` token before any `EndOfStream` error,
+    /// so a consumer can tell the end of input apart from errors by looking at the last token.
+    ///
+    /// Disabled by default.
+    pub fn set_eof_token(&mut self, enabled: bool) {
+        self.eof_token = enabled;
+    }
+
+    /// Current state of the lexer, which decides how ambiguous input like `/` and `}` is read.
+    pub fn get_state(&self) -> LexerState {
+        self.state
+    }
+
+    /// Set the state used for all following reads. To read a single token with another state, use
+    /// `ReReadWithState::read_with_state` instead.
     pub fn set_state(&mut self, state: LexerState) {
         self.state = state;
     }
 
+    /// Snapshot of the lexer at the current position, which is a token boundary unless called in
+    /// the middle of reading a token. Used for incremental lexing, see `resume`.
+    pub fn checkpoint(&self) -> LexerCheckpoint {
+        LexerCheckpoint {
+            position: self.reader.position(),
+            state: self.state,
+            first_on_line: self.first_on_line,
+        }
+    }
+
+    /// Continue reading from `checkpoint`, the following tokens are the same as the tokens that
+    /// followed when the checkpoint was made, given that the source before and after the
+    /// checkpoint position is unchanged.
+    ///
+    /// The checkpoint may come from another lexer, i.e. from lexing the source before an edit.
+    /// Returns error if the checkpoint position is outside of the source or not at a char
+    /// boundary, which may happen if the source got shorter.
+    pub fn resume(&mut self, checkpoint: LexerCheckpoint) -> Result<()> {
+        if !self.data.is_char_boundary(checkpoint.position) {
+            return Err(Error::syntax_error(
+                format!(
+                    "Checkpoint position {} is not within the source",
+                    checkpoint.position
+                ),
+                (checkpoint.position, checkpoint.position),
+            ));
+        }
+
+        self.seek(SeekFrom::Start(checkpoint.position as u64))
+            .expect("Position is a char boundary");
+        self.state = checkpoint.state;
+        self.first_on_line = checkpoint.first_on_line;
+        Ok(())
+    }
+
+    /// Read all remaining tokens until end of stream.
     pub fn read_all(&mut self) -> Result<Vec<Token>> {
         let mut tokens = Vec::new();
 
@@ -113,12 +210,24 @@ impl<'a> Lexer<'a> {
         self.skip_comments_and_white_spaces()?;
 
         if self.is_end() {
+            if self.eof_token && !self.eof_read {
+                self.eof_read = true;
+                let end = self.data.len();
+                return Ok(Token::new(
+                    TokenValue::Eof,
+                    self.first_on_line,
+                    Span::new(end, end),
+                ));
+            }
+
             return Err(Error::end_of_stream());
         }
 
         let current = self.reader.current()?;
 
         let start = self.reader.position();
+        self.legacy_octal = false;
+        self.escaped = false;
         let value = match current {
             '/' if self.state.regex_allowed => self.read_regexp_literal(),
             // <op>=
@@ -177,9 +286,18 @@ impl<'a> Lexer<'a> {
             ')' => produce!(self, 1, punct!(")")),
             '~' => produce!(self, 1, punct!("~")),
             ':' => produce!(self, 1, punct!(":")),
+            '@' => produce!(self, 1, punct!("@")),
             '!' => produce!(self, 1, punct!("!")),
-            '&' => produce!(self, peek: '&' ? punct!("&&") ; punct!("&")),
-            '|' => produce!(self, peek: '|' ? punct!("||") ; punct!("|")),
+            '&' if self.reader.peek().ok() == Some(&'&') => {
+                self.reader.consume()?;
+                produce!(self, peek: '=' ? punct!("&&=") ; punct!("&&"))
+            }
+            '|' if self.reader.peek().ok() == Some(&'|') => {
+                self.reader.consume()?;
+                produce!(self, peek: '=' ? punct!("||=") ; punct!("||"))
+            }
+            '&' => produce!(self, 1, punct!("&")),
+            '|' => produce!(self, 1, punct!("|")),
             '+' => produce!(self, peek: '+' ? punct!("++") ; punct!("+")),
             '-' => produce!(self, peek: '-' ? punct!("--") ; punct!("-")),
             '?' => {
@@ -191,7 +309,12 @@ impl<'a> Lexer<'a> {
                     }
                     Ok(&'?') => {
                         self.reader.consume()?;
-                        punct!("??")
+                        if self.reader.current().ok() == Some(&'=') {
+                            self.reader.consume()?;
+                            punct!("??=")
+                        } else {
+                            punct!("??")
+                        }
                     }
                     _ => punct!("?"),
                 })
@@ -249,7 +372,9 @@ impl<'a> Lexer<'a> {
             return self.read();
         }
 
-        let token = Token::new(value, self.first_on_line, (start, end));
+        let mut token = Token::new(value, self.first_on_line, (start, end));
+        token.legacy_octal = self.legacy_octal;
+        token.escaped = self.escaped;
         self.first_on_line = false;
 
         Ok(token)
@@ -264,7 +389,7 @@ impl<'a> Lexer<'a> {
                     self.skip_single_line_comment();
                     self.first_on_line = true;
                 }
-                Ok('<') if self.reader.peek().ok() == Some(&'!') => {
+                Ok('<') if self.data[self.reader.position()..].starts_with("<!--") => {
                     if !self.state.html_comment_allowed {
                         let position = self.reader.position();
                         return Err(Error::syntax_error(
@@ -336,19 +461,32 @@ impl<'a> Lexer<'a> {
     }
 
     fn read_identifier_or_keyword(&mut self) -> Result<TokenValue> {
-        let word = self.read_identifier_or_keyword_expand_unicode()?;
-        let value = if let Ok(keyword) = word.parse() {
-            TokenValue::Keyword(keyword)
-        } else {
-            TokenValue::Identifier(word)
-        };
+        // The buffer is reused between words, the only allocation is for the identifier atom.
+        let mut word = mem::take(&mut self.word_buffer);
+        word.clear();
+
+        let value = self
+            .read_identifier_or_keyword_expand_unicode(&mut word)
+            .map(|()| {
+                if let Ok(keyword) = word.parse() {
+                    TokenValue::Keyword(keyword)
+                } else {
+                    TokenValue::Identifier(self.atom(&word))
+                }
+            });
 
-        Ok(value)
+        self.word_buffer = word;
+        value
     }
 
-    fn read_identifier_or_keyword_expand_unicode(&mut self) -> Result<String> {
-        let mut word = String::new();
+    fn atom(&mut self, word: &str) -> Atom {
+        match &mut self.interner {
+            Some(interner) => interner.intern(word),
+            None => Atom::from(word),
+        }
+    }
 
+    fn read_identifier_or_keyword_expand_unicode(&mut self, word: &mut String) -> Result<()> {
         loop {
             match self.reader.current() {
                 Ok(c) if c.is_part_of_identifier() => word.push(self.reader.consume()?),
@@ -360,7 +498,7 @@ impl<'a> Lexer<'a> {
             }
         }
 
-        Ok(word)
+        Ok(())
     }
 
     fn read_template_literal_head(&mut self) -> Result<TokenValue> {
@@ -399,14 +537,10 @@ impl<'a> Lexer<'a> {
         };
 
         let span = Span::new(span_start, self.reader.position());
-        Ok(Token {
-            span,
-            first_on_line: false,
-            value,
-        })
+        Ok(Token::new(value, false, span))
     }
 
-    /// Returns literal string and what ended it.
+    /// Returns the raw literal string, escape sequences included, and what ended it.
     fn read_until_end_of_template_literal_part(&mut self) -> Result<(String, &'static str)> {
         let mut result = String::new();
 
@@ -423,9 +557,7 @@ impl<'a> Lexer<'a> {
             }
 
             escape = c == '\\' && !escape;
-            if !escape {
-                result.push(c);
-            }
+            result.push(c);
         }
     }
 
@@ -458,6 +590,7 @@ impl<'a> Lexer<'a> {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct LexerState {
     html_comment_allowed: bool,
     regex_allowed: bool,
@@ -487,6 +620,26 @@ impl LexerState {
     }
 }
 
+/// Position and state of the lexer between two tokens, see `Lexer::checkpoint`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LexerCheckpoint {
+    position: usize,
+    state: LexerState,
+    first_on_line: bool,
+}
+
+impl LexerCheckpoint {
+    /// Position in the source where reading continues.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Lexer state used when reading continues.
+    pub fn state(&self) -> LexerState {
+        self.state
+    }
+}
+
 impl Default for LexerState {
     fn default() -> Self {
         Self {
@@ -508,8 +661,19 @@ impl Seek for Lexer<'_> {
         };
 
         let offset = pos as usize;
-        self.reader = PeekReader::with_offset(self.data[offset..].char_indices(), offset).unwrap();
+        let Some(data) = self.data.get(offset..) else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Seek position is not a char boundary of the source",
+            ));
+        };
+
+        // The byte order mark is the only non-ASCII character an ASCII source may start with.
+        let is_ascii = self.is_ascii && !data.starts_with('\u{feff}');
+        let chars = StrReader::with_known_ascii(data, is_ascii);
+        self.reader = PeekReader::with_offset(chars, offset).unwrap();
         self.first_on_line = true;
+        self.eof_read = false;
 
         Ok(pos)
     }
@@ -553,7 +717,7 @@ impl ReReadWithState<Token> for Lexer<'_> {
     /// Identifier("a")
     /// Punctuator("=")
     /// Identifier("b")
-    /// Literal(Regexp("/c/g"))
+    /// Literal(Regexp { pattern: "c", flags: "g" })
     /// ```
     fn read_with_state(
         &mut self,
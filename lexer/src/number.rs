@@ -11,13 +11,7 @@ impl<'a> Lexer<'a> {
             Ok('x' | 'X') if current == &'0' => self.read_hex_string()?,
             Ok('o' | 'O') if current == &'0' => self.read_octal_string()?,
             Ok('b' | 'B') if current == &'0' => self.read_binary_string()?,
-            Ok('0'..='9') if current == &'0' => {
-                let position = self.reader.position();
-                return Err(Error::syntax_error(
-                    "Zero prefixed numbers are deprecated and not supported".to_owned(),
-                    (position, position + 1),
-                ));
-            }
+            Ok('0'..='9') if current == &'0' => self.read_legacy_octal_string()?,
             _ => self.read_decimal_string()?,
         };
 
@@ -25,7 +19,7 @@ impl<'a> Lexer<'a> {
             .reader
             .current()
             .ok()
-            .map_or(false, CodePoint::is_start_of_identifier)
+            .is_some_and(CodePoint::is_start_of_identifier)
         {
             let position = self.reader.position();
             return Err(Error::syntax_error(
@@ -78,6 +72,21 @@ impl<'a> Lexer<'a> {
         Ok(number_string)
     }
 
+    /// Reads a zero prefixed number, which is either a `LegacyOctalIntegerLiteral` like `0777` or
+    /// a `NonOctalDecimalIntegerLiteral` like `089`. Neither may contain numeric separators, and
+    /// both are flagged as legacy octal since they are not allowed in strict mode.
+    fn read_legacy_octal_string(&mut self) -> Result<String> {
+        let span_start = self.reader.position();
+        self.legacy_octal = true;
+
+        let mut number_string = self.reader.read_while(char::is_ascii_digit)?;
+        if number_string.contains(['8', '9']) {
+            self.read_decimal_fraction_and_exponent(span_start, &mut number_string)?;
+        }
+
+        Ok(number_string)
+    }
+
     fn read_decimal_string(&mut self) -> Result<String> {
         let span_start = self.reader.position();
 
@@ -86,11 +95,20 @@ impl<'a> Lexer<'a> {
         let integer_part = self.read_number_string(span_start, char::is_ascii_digit)?;
         number_string.push_str(&integer_part);
 
+        self.read_decimal_fraction_and_exponent(span_start, &mut number_string)?;
+        Ok(number_string)
+    }
+
+    fn read_decimal_fraction_and_exponent(
+        &mut self,
+        span_start: usize,
+        number_string: &mut String,
+    ) -> Result<()> {
         if self.reader.current().ok() == Some(&'.') {
             number_string.push(self.reader.consume()?);
 
             let fractional_part =
-                self.read_number_string(span_start, |c| ('0'..='9').contains(c))?;
+                self.read_number_string(span_start, |c: &char| c.is_ascii_digit())?;
             number_string.push_str(&fractional_part);
         }
 
@@ -106,7 +124,7 @@ impl<'a> Lexer<'a> {
             number_string.push_str(&exponential_part);
         }
 
-        Ok(number_string)
+        Ok(())
     }
 
     fn expect_read_number_string(
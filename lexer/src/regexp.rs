@@ -1,54 +1,65 @@
 use crate::error::Error;
 use crate::{CodePoint, Lexer, TokenValue};
-use fajt_ast::Literal;
+use fajt_ast::{LitRegexp, Literal};
 
 type Result<T> = std::result::Result<T, Error>;
 
 impl Lexer<'_> {
     pub(super) fn read_regexp_literal(&mut self) -> Result<TokenValue> {
         let span_start = self.reader.position();
-        let mut result = String::new();
+        let mut pattern = String::new();
         let regexp_start = self.reader.consume()?;
         debug_assert_eq!(regexp_start, '/');
 
-        result.push(regexp_start);
-
         loop {
             let c = self.reader.consume()?;
-            result.push(c);
+            if c == '/' {
+                break;
+            }
+
+            pattern.push(c);
 
             match c {
-                '\n' => {
+                c if c.is_ecma_line_terminator() => {
                     let span_end = self.reader.position();
                     return Err(Error::syntax_error(
                         "Unterminated regular expression literal".to_owned(),
-                        (span_start, span_end - 1),
+                        (span_start, span_end - c.len_utf8()),
                     ));
                 }
-                '/' => break,
                 '\\' if !self.reader.current()?.is_ecma_line_terminator() => {
-                    result.push(self.reader.consume()?)
+                    pattern.push(self.reader.consume()?)
                 }
-                '[' => result.push_str(&self.read_regexp_group_body()?),
+                '[' => pattern.push_str(&self.read_regexp_group_body(span_start)?),
                 _ => {}
             }
         }
 
         let flags = self.reader.read_while(char::is_part_of_identifier)?;
-        result.push_str(&flags);
-
-        Ok(TokenValue::Literal(Literal::Regexp(result)))
+        Ok(TokenValue::Literal(Literal::Regexp(LitRegexp {
+            pattern,
+            flags,
+        })))
     }
 
-    fn read_regexp_group_body(&mut self) -> Result<String> {
+    fn read_regexp_group_body(&mut self, span_start: usize) -> Result<String> {
         let mut result = String::new();
         loop {
             let c = self.reader.consume()?;
             result.push(c);
 
             match c {
+                c if c.is_ecma_line_terminator() => {
+                    let span_end = self.reader.position();
+                    return Err(Error::syntax_error(
+                        "Unterminated regular expression literal".to_owned(),
+                        (span_start, span_end - c.len_utf8()),
+                    ));
+                }
                 ']' => break,
-                '\\' => result.push(self.reader.consume()?),
+                '\\' if !self.reader.current()?.is_ecma_line_terminator() => {
+                    result.push(self.reader.consume()?)
+                }
                 _ => {}
             }
         }
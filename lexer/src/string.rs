@@ -6,11 +6,14 @@ impl<'a> Lexer<'a> {
         let delimiter = self.reader.consume()?;
         debug_assert!(delimiter == '"' || delimiter == '\'');
 
+        let raw_start = self.reader.position();
         let mut value = String::new();
         self.read_until_unescaped_delimiter(delimiter, &mut value)?;
+        let raw_end = self.reader.position() - delimiter.len_utf8();
 
         Ok(TokenValue::Literal(Literal::String(LitString {
             value,
+            raw: self.data[raw_start..raw_end].to_owned(),
             delimiter,
         })))
     }
@@ -38,11 +41,82 @@ impl<'a> Lexer<'a> {
     }
 
     fn read_escape_sequence(&mut self, result: &mut String) -> Result<()> {
+        let span_start = self.reader.position() - 1;
         let c = self.reader.consume()?;
-        result.push(c);
+        self.escaped = true;
 
-        if c == '\r' && self.reader.current().ok() == Some(&'\n') {
-            result.push(self.reader.consume()?);
+        if c == 'u' {
+            let code_point = self.read_string_unicode_escape(span_start)?;
+
+            // Lone surrogates are valid in string values but can't be represented in a `String`,
+            // so those are kept as written.
+            match char::from_u32(code_point) {
+                Some(c) => result.push(c),
+                None => result.push_str(&self.data[span_start..self.reader.position()]),
+            }
+            return Ok(());
+        }
+
+        if c.is_ascii_digit() && (c != '0' || self.reader.current().is_ok_and(char::is_ascii_digit))
+        {
+            self.read_legacy_octal_escape(c)?;
+            result.push_str(&self.data[span_start..self.reader.position()]);
+            return Ok(());
+        }
+
+        match c {
+            'b' => result.push('\u{8}'),
+            'f' => result.push('\u{c}'),
+            'n' => result.push('\n'),
+            'r' => result.push('\r'),
+            't' => result.push('\t'),
+            'v' => result.push('\u{b}'),
+            '0' => result.push('\0'),
+            'x' => {
+                let high = self.read_hex_char(span_start)?;
+                let low = self.read_hex_char(span_start)?;
+                let code_point = high.to_digit(16).unwrap() * 16 + low.to_digit(16).unwrap();
+                result.push(char::from_u32(code_point).unwrap());
+            }
+            // Line continuations are not part of the value.
+            '\r' => {
+                if self.reader.current().ok() == Some(&'\n') {
+                    self.reader.consume()?;
+                }
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            c => result.push(c),
+        }
+
+        Ok(())
+    }
+
+    /// Validates the code point of a `\uXXXX` or `\u{X...}` escape, which may be a lone
+    /// surrogate but must not exceed `0x10FFFF`.
+    fn read_string_unicode_escape(&mut self, span_start: usize) -> Result<u32> {
+        match self.reader.current()? {
+            '{' => self.read_code_point(span_start),
+            _ => self.read_4digit_hex(span_start),
+        }
+    }
+
+    /// Consumes the rest of a `LegacyOctalEscapeSequence` like `\012`, or a
+    /// `NonOctalDecimalEscapeSequence` like `\8`, where `first` is the already consumed digit.
+    /// These are kept as written since they are only allowed in sloppy mode.
+    fn read_legacy_octal_escape(&mut self, first: char) -> Result<()> {
+        self.legacy_octal = true;
+
+        let max_length = match first {
+            '0'..='3' => 3,
+            '4'..='7' => 2,
+            _ => return Ok(()),
+        };
+
+        for _ in 1..max_length {
+            if !matches!(self.reader.current(), Ok('0'..='7')) {
+                break;
+            }
+            self.reader.consume()?;
         }
 
         Ok(())
@@ -1,4 +1,4 @@
-use fajt_ast::{Literal, Span};
+use fajt_ast::{Atom, Literal, Span};
 use fajt_macros::FromString;
 use serde::{Deserialize, Serialize};
 
@@ -66,6 +66,7 @@ bitflags! {
 /// ```
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, FromString, Serialize, Deserialize)]
 #[from_string_macro("keyword")]
+#[from_string_variants]
 #[from_string_macro_rules(
     ($variant:ident) => {
         $crate::token::TokenValue::Keyword($crate::token::Keyword::$variant)
@@ -74,6 +75,7 @@ bitflags! {
 pub enum Keyword {
     Await,
     As,
+    Assert,
     Async,
     Break,
     Case,
@@ -128,16 +130,33 @@ pub enum Keyword {
 }
 
 impl Keyword {
+    /// True if the keyword is reserved in at least some context, i.e. `yield` and `let` are
+    /// reserved words even if they are allowed as identifiers outside of generators and strict
+    /// mode. See `is_allowed_as_identifier` for the context rules.
+    pub fn is_reserved_word(&self) -> bool {
+        !self.is_contextual()
+    }
+
+    /// True if the keyword is never reserved, it only has a meaning in specific positions, i.e.
+    /// `of` in `for (a of b)` or `get` in object literals.
+    pub fn is_contextual(&self) -> bool {
+        matches!(
+            self,
+            Self::As
+                | Self::Assert
+                | Self::Async
+                | Self::From
+                | Self::Get
+                | Self::Of
+                | Self::Set
+                | Self::Target
+        )
+    }
+
     /// True if the keyword is allowed to be an identifier in the context provided.
     pub fn is_allowed_as_identifier(&self, ctx: KeywordContext) -> bool {
         match self {
-            Self::As
-            | Self::Async
-            | Self::From
-            | Self::Get
-            | Self::Of
-            | Self::Set
-            | Self::Target => true,
+            _ if self.is_contextual() => true,
             Self::Await if !ctx.contains(KeywordContext::AWAIT) => true,
             Self::Yield if !ctx.intersects(KeywordContext::YIELD | KeywordContext::STRICT) => true,
             Self::Implements
@@ -171,6 +190,7 @@ impl Keyword {
 /// ```
 #[derive(Debug, PartialOrd, PartialEq, FromString, Clone, Serialize, Deserialize)]
 #[from_string_macro("punct")]
+#[from_string_variants]
 #[from_string_macro_rules(
     ($variant:ident) => {
         $crate::token::TokenValue::Punctuator($crate::token::Punctuator::$variant)
@@ -267,10 +287,14 @@ pub enum Punctuator {
     Ampersand,
     #[from_string("&&")]
     DoubleAmpersand,
+    #[from_string("&&=")]
+    DoubleAmpersandEqual,
     #[from_string("|")]
     Pipe,
     #[from_string("||")]
     DoublePipe,
+    #[from_string("||=")]
+    DoublePipeEqual,
     #[from_string("^")]
     Caret,
     #[from_string("!")]
@@ -281,10 +305,14 @@ pub enum Punctuator {
     QuestionMark,
     #[from_string("??")]
     DoubleQuestionMark,
+    #[from_string("??=")]
+    DoubleQuestionMarkEqual,
     #[from_string("?.")]
     QuestionMarkDot,
     #[from_string(":")]
     Colon,
+    #[from_string("@")]
+    At,
 }
 
 #[macro_export]
@@ -299,19 +327,26 @@ macro_rules! literal(
         )
     };
     (string, $type:expr, $value:expr) => {
+        $crate::literal!(string, $type, $value, $value)
+    };
+    (string, $type:expr, $value:expr, $raw:expr) => {
          $crate::token::TokenValue::Literal(
             fajt_ast::Literal::String(
                 fajt_ast::LitString {
                     value: $value.to_owned(),
+                    raw: $raw.to_owned(),
                     delimiter: $type,
                 }
             )
         )
     };
-    (regexp, $value:expr) => {
+    (regexp, $pattern:expr, $flags:expr) => {
          $crate::token::TokenValue::Literal(
             fajt_ast::Literal::Regexp(
-                $value.to_owned(),
+                fajt_ast::LitRegexp {
+                    pattern: $pattern.to_owned(),
+                    flags: $flags.to_owned(),
+                }
             )
         )
     }
@@ -320,12 +355,67 @@ macro_rules! literal(
 #[derive(Debug, Clone, PartialOrd, PartialEq, Serialize, Deserialize)]
 pub enum TokenValue {
     Keyword(Keyword),
-    Identifier(String),
+    Identifier(Atom),
     Punctuator(Punctuator),
     Literal(Literal),
     TemplateHead(String),
     TemplateMiddle(String),
     TemplateTail(String),
+    /// Zero width token at the end of the source, only read when enabled with
+    /// `Lexer::set_eof_token`.
+    Eof,
+}
+
+/// Category of a token, i.e. for syntax highlighting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenCategory {
+    Keyword,
+    Identifier,
+    Punctuator,
+    Literal,
+    /// Part of a template literal with substitutions, template literals without substitutions are
+    /// `Literal`.
+    Template,
+    Eof,
+}
+
+impl TokenValue {
+    pub fn category(&self) -> TokenCategory {
+        match self {
+            TokenValue::Keyword(_) => TokenCategory::Keyword,
+            TokenValue::Identifier(_) => TokenCategory::Identifier,
+            TokenValue::Punctuator(_) => TokenCategory::Punctuator,
+            TokenValue::Literal(_) => TokenCategory::Literal,
+            TokenValue::TemplateHead(_)
+            | TokenValue::TemplateMiddle(_)
+            | TokenValue::TemplateTail(_) => TokenCategory::Template,
+            TokenValue::Eof => TokenCategory::Eof,
+        }
+    }
+
+    pub fn is_keyword(&self) -> bool {
+        self.category() == TokenCategory::Keyword
+    }
+
+    pub fn is_identifier(&self) -> bool {
+        self.category() == TokenCategory::Identifier
+    }
+
+    pub fn is_punctuator(&self) -> bool {
+        self.category() == TokenCategory::Punctuator
+    }
+
+    pub fn is_literal(&self) -> bool {
+        self.category() == TokenCategory::Literal
+    }
+
+    pub fn is_template(&self) -> bool {
+        self.category() == TokenCategory::Template
+    }
+
+    pub fn is_eof(&self) -> bool {
+        self.category() == TokenCategory::Eof
+    }
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Serialize, Deserialize)]
@@ -333,6 +423,13 @@ pub struct Token {
     pub value: TokenValue,
     pub first_on_line: bool,
     pub span: Span,
+    /// `true` if the token is a legacy octal number like `0777`, or a string with a legacy octal
+    /// escape like `"\012"`. Both are only allowed in sloppy mode.
+    #[serde(default)]
+    pub legacy_octal: bool,
+    /// `true` if the token is a string literal with an escape sequence or a line continuation.
+    #[serde(default)]
+    pub escaped: bool,
 }
 
 impl Token {
@@ -341,6 +438,8 @@ impl Token {
             value,
             first_on_line,
             span: span.into(),
+            legacy_octal: false,
+            escaped: false,
         }
     }
 }
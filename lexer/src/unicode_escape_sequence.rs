@@ -58,7 +58,7 @@ impl Lexer<'_> {
         Ok(char)
     }
 
-    fn read_code_point(&mut self, span_start: usize) -> Result<u32> {
+    pub(super) fn read_code_point(&mut self, span_start: usize) -> Result<u32> {
         let open_bracket = self.reader.consume()?;
         debug_assert_eq!(open_bracket, '{');
 
@@ -84,7 +84,7 @@ impl Lexer<'_> {
         }
     }
 
-    fn read_4digit_hex(&mut self, span_start: usize) -> Result<u32> {
+    pub(super) fn read_4digit_hex(&mut self, span_start: usize) -> Result<u32> {
         let mut hex = String::with_capacity(4);
         hex.push(self.read_hex_char(span_start)?);
         hex.push(self.read_hex_char(span_start)?);
@@ -95,7 +95,7 @@ impl Lexer<'_> {
         Ok(u32::from_str_radix(&hex, 16).unwrap())
     }
 
-    fn read_hex_char(&mut self, span_start: usize) -> Result<char> {
+    pub(super) fn read_hex_char(&mut self, span_start: usize) -> Result<char> {
         match self.reader.consume()? {
             c @ ('0'..='9' | 'a'..='f' | 'A'..='F') => Ok(c),
             _ => {
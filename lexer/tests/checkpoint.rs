@@ -0,0 +1,93 @@
+use fajt_lexer::{Lexer, LexerState};
+
+const SOURCE: &str = "let a = 1;\nfunction f() {\n  return /* a */ a;\n}\n// c\nf()";
+
+#[test]
+fn resume_produces_same_tokens_as_full_lex() {
+    let mut lexer = Lexer::new(SOURCE).unwrap();
+    let all = lexer.read_all().unwrap();
+
+    for split in 0..all.len() {
+        let mut lexer = Lexer::new(SOURCE).unwrap();
+        for _ in 0..split {
+            lexer.read().unwrap();
+        }
+        let checkpoint = lexer.checkpoint();
+
+        let mut resumed = Lexer::new(SOURCE).unwrap();
+        resumed.resume(checkpoint).unwrap();
+        assert_eq!(
+            resumed.read_all().unwrap(),
+            &all[split..],
+            "Split at {}",
+            split
+        );
+    }
+}
+
+#[test]
+fn resume_after_edit() {
+    let mut lexer = Lexer::new(SOURCE).unwrap();
+    lexer.read().unwrap();
+    lexer.read().unwrap();
+    let checkpoint = lexer.checkpoint();
+    assert_eq!(checkpoint.position(), 5);
+
+    // Edit after the checkpoint, `a = 1` to `a = 22`.
+    let edited = SOURCE.replacen("= 1", "= 22", 1);
+    let mut expected = Lexer::new(&edited).unwrap();
+    let expected = expected.read_all().unwrap();
+
+    let mut resumed = Lexer::new(&edited).unwrap();
+    resumed.resume(checkpoint).unwrap();
+    assert_eq!(resumed.read_all().unwrap(), &expected[2..]);
+}
+
+#[test]
+fn resume_keeps_state() {
+    let source = "a\n<!-- b";
+    let mut lexer = Lexer::new(source).unwrap();
+    lexer.set_state(LexerState::default().with_html_comments_allowed(false));
+    lexer.read().unwrap();
+    let checkpoint = lexer.checkpoint();
+
+    let mut resumed = Lexer::new(source).unwrap();
+    resumed.resume(checkpoint).unwrap();
+    assert_eq!(resumed.checkpoint(), checkpoint);
+    assert!(resumed.read().is_err());
+}
+
+#[test]
+fn resume_inside_template() {
+    let source = "`x${a}y`";
+    let mut lexer = Lexer::new(source).unwrap();
+    lexer.read().unwrap();
+    lexer.read().unwrap();
+    lexer.set_state(LexerState::inside_template());
+    let checkpoint = lexer.checkpoint();
+    let tail = lexer.read().unwrap();
+
+    let mut resumed = Lexer::new(source).unwrap();
+    resumed.resume(checkpoint).unwrap();
+    assert_eq!(resumed.read().unwrap(), tail);
+}
+
+#[test]
+fn resume_on_shorter_source() {
+    let mut lexer = Lexer::new(SOURCE).unwrap();
+    for _ in 0..5 {
+        lexer.read().unwrap();
+    }
+    let checkpoint = lexer.checkpoint();
+    assert_eq!(checkpoint.position(), 10);
+
+    let mut resumed = Lexer::new("let").unwrap();
+    assert!(resumed.resume(checkpoint).is_err());
+
+    // Position inside the multi byte `é`.
+    let mut resumed = Lexer::new("let a = 'é';").unwrap();
+    assert!(resumed.resume(checkpoint).is_err());
+
+    // Failed resume keeps the lexer usable.
+    assert_eq!(resumed.read_all().unwrap().len(), 5);
+}
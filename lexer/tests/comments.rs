@@ -81,6 +81,17 @@ fn single_line_html_open_comment_when_not_allowed() {
     )
 }
 
+#[test]
+fn less_than_not_is_not_html_open_comment() {
+    let tokens = lex("a<!b");
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(tokens[1].value, punct!("<"));
+    assert_eq!(tokens[2].value, punct!("!"));
+
+    let tokens = lex("a<!-b");
+    assert_eq!(tokens.len(), 5);
+}
+
 #[test]
 fn empty_single_line_html_open_comment() {
     let tokens = lex("<!--");
@@ -131,3 +142,21 @@ fn single_line_html_close_comment_comment_before() {
     let tokens = lex("/* hello */ /* hello */ --> Hello, I am comment.\na");
     assert_eq!(tokens.len(), 1);
 }
+
+#[test]
+fn single_line_comment_ends_at_carriage_return() {
+    let tokens = lex("a // Hello, I am comment.\rb");
+    assert!(tokens[1].first_on_line);
+}
+
+#[test]
+fn single_line_comment_ends_at_crlf() {
+    let tokens = lex("a // Hello, I am comment.\r\nb");
+    assert!(tokens[1].first_on_line);
+}
+
+#[test]
+fn carriage_return_in_multi_line_comment() {
+    let tokens = lex("a/*\r*/b");
+    assert!(tokens[1].first_on_line);
+}
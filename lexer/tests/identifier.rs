@@ -7,7 +7,7 @@ fn ascii() {
     assert_lexer!(
         input: "abcdef1234",
         output: [
-            (Identifier("abcdef1234".to_owned()), (0, 10)),
+            (Identifier("abcdef1234".into()), (0, 10)),
         ]
     );
 }
@@ -17,7 +17,7 @@ fn non_ascii() {
     assert_lexer!(
         input: "\u{0636}\u{05DC}\u{0998}",
         output: [
-            (Identifier("\u{0636}\u{05DC}\u{0998}".to_owned()), (0, 5)),
+            (Identifier("\u{0636}\u{05DC}\u{0998}".into()), (0, 7)),
         ]
     );
 }
@@ -27,7 +27,7 @@ fn unicode_escape_sequence_hex_ident_start() {
     assert_lexer!(
         input: r#"\u0069dent"#,
         output: [
-            (Identifier("ident".to_owned()), (0, 10)),
+            (Identifier("ident".into()), (0, 10)),
         ]
     );
 }
@@ -37,7 +37,7 @@ fn unicode_escape_sequence_hex_ident_middle() {
     assert_lexer!(
         input: r#"id\u0065nt"#,
         output: [
-            (Identifier("ident".to_owned()), (0, 10)),
+            (Identifier("ident".into()), (0, 10)),
         ]
     );
 }
@@ -47,7 +47,7 @@ fn unicode_escape_sequence_hex_mixed_case() {
     assert_lexer!(
         input: r#"f\u004F\u004f"#,
         output: [
-            (Identifier("fOO".to_owned()), (0, 13)),
+            (Identifier("fOO".into()), (0, 13)),
         ]
     );
 }
@@ -73,7 +73,7 @@ fn unicode_escape_sequence_hex_valid_id_continue() {
     assert_lexer!(
         input: r#"fo\u0030"#,
         output: [
-            (Identifier("fo0".to_owned()), (0, 8)),
+            (Identifier("fo0".into()), (0, 8)),
         ]
     );
 }
@@ -91,7 +91,7 @@ fn unicode_escape_sequence_code_point_ident_start() {
     assert_lexer!(
         input: r#"\u{0069}dent"#,
         output: [
-            (Identifier("ident".to_owned()), (0, 12)),
+            (Identifier("ident".into()), (0, 12)),
         ]
     );
 }
@@ -101,7 +101,7 @@ fn unicode_escape_sequence_code_point_ident_middle() {
     assert_lexer!(
         input: r#"id\u{0065}nt"#,
         output: [
-            (Identifier("ident".to_owned()), (0, 12)),
+            (Identifier("ident".into()), (0, 12)),
         ]
     );
 }
@@ -111,7 +111,7 @@ fn unicode_escape_sequence_code_point_mixed_case() {
     assert_lexer!(
         input: r#"f\u{4F}\u{4f}"#,
         output: [
-            (Identifier("fOO".to_owned()), (0, 13)),
+            (Identifier("fOO".into()), (0, 13)),
         ]
     );
 }
@@ -121,7 +121,7 @@ fn unicode_escape_sequence_code_point_short() {
     assert_lexer!(
         input: r#"\u{69}"#,
         output: [
-            (Identifier("i".to_owned()), (0, 6)),
+            (Identifier("i".into()), (0, 6)),
         ]
     );
 }
@@ -147,7 +147,7 @@ fn unicode_escape_sequence_code_point_valid_id_continue() {
     assert_lexer!(
         input: r#"fo\u{30}"#,
         output: [
-            (Identifier("fo0".to_owned()), (0, 8)),
+            (Identifier("fo0".into()), (0, 8)),
         ]
     );
 }
@@ -173,7 +173,7 @@ fn unicode_escape_sequence_code_point_many_zeros() {
     assert_lexer!(
         input: r#"\u{000000000000000065}"#,
         output: [
-            (Identifier("e".to_owned()), (0, 22)),
+            (Identifier("e".into()), (0, 22)),
         ]
     );
 }
@@ -215,7 +215,7 @@ fn zwnj_ident_continue() {
     assert_lexer!(
         input: "id\u{200c}ent",
         output: [
-            (Identifier("id\u{200c}ent".to_owned()), (0, 8)),
+            (Identifier("id\u{200c}ent".into()), (0, 8)),
         ]
     );
 }
@@ -233,7 +233,7 @@ fn zwj_ident_continue() {
     assert_lexer!(
         input: "id\u{200d}ent",
         output: [
-            (Identifier("id\u{200d}ent".to_owned()), (0, 8)),
+            (Identifier("id\u{200d}ent".into()), (0, 8)),
         ]
     );
 }
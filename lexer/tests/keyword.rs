@@ -0,0 +1,141 @@
+use fajt_lexer::token::{Keyword, KeywordContext, TokenValue};
+use fajt_lexer::Lexer;
+use std::str::FromStr;
+
+const KEYWORDS: [&str; 54] = [
+    "await",
+    "as",
+    "assert",
+    "async",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "get",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "of",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "set",
+    "static",
+    "super",
+    "switch",
+    "target",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+#[test]
+fn reserved_word() {
+    assert!(Keyword::Function.is_reserved_word());
+    assert!(!Keyword::Function.is_contextual());
+    assert!(!Keyword::Function.is_allowed_as_identifier(KeywordContext::empty()));
+}
+
+#[test]
+fn contextual() {
+    assert!(Keyword::Of.is_contextual());
+    assert!(!Keyword::Of.is_reserved_word());
+    assert!(Keyword::Of.is_allowed_as_identifier(KeywordContext::all()));
+}
+
+#[test]
+fn context_dependent() {
+    assert!(Keyword::Yield.is_reserved_word());
+    assert!(!Keyword::Yield.is_contextual());
+    assert!(Keyword::Yield.is_allowed_as_identifier(KeywordContext::empty()));
+    assert!(!Keyword::Yield.is_allowed_as_identifier(KeywordContext::YIELD));
+    assert!(!Keyword::Yield.is_allowed_as_identifier(KeywordContext::STRICT));
+}
+
+#[test]
+fn strict_mode_reserved_words() {
+    for keyword in [Keyword::Let, Keyword::Static, Keyword::Implements] {
+        assert!(keyword.is_reserved_word());
+        assert!(keyword.is_allowed_as_identifier(KeywordContext::empty()));
+        assert!(!keyword.is_allowed_as_identifier(KeywordContext::STRICT));
+    }
+}
+
+#[test]
+fn as_str_round_trip() {
+    for keyword in KEYWORDS {
+        let parsed = Keyword::from_str(keyword).unwrap();
+        assert_eq!(parsed.as_str(), keyword);
+        assert_eq!(Keyword::from_str(parsed.as_str()), Ok(parsed));
+    }
+}
+
+#[test]
+fn variants() {
+    assert_eq!(Keyword::VARIANTS.len(), KEYWORDS.len());
+    assert_eq!(Keyword::VARIANTS[0], ("await", Keyword::Await));
+    assert!(Keyword::VARIANTS.contains(&("instanceof", Keyword::Instanceof)));
+
+    for (string, keyword) in Keyword::VARIANTS {
+        assert_eq!(keyword.as_str(), *string);
+        assert_eq!(Keyword::from_str(string).as_ref(), Ok(keyword));
+    }
+}
+
+#[test]
+fn all_keywords_are_lexed_as_keywords() {
+    for (string, keyword) in Keyword::VARIANTS {
+        let token = Lexer::new(string).unwrap().read().unwrap();
+        assert_eq!(token.value, TokenValue::Keyword(keyword.clone()));
+    }
+}
+
+#[test]
+fn words_similar_to_keywords_are_identifiers() {
+    for word in [
+        "awai",
+        "awaits",
+        "Await",
+        "ass",
+        "i",
+        "n",
+        "instanceOf",
+        "implementss",
+        "x",
+    ] {
+        assert!(Keyword::from_str(word).is_err());
+
+        let token = Lexer::new(word).unwrap().read().unwrap();
+        assert_eq!(token.value, TokenValue::Identifier(word.into()));
+    }
+}
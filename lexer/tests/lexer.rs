@@ -1,7 +1,8 @@
 use fajt_ast::Span;
-use fajt_common::io::PeekRead;
+use fajt_common::io::{PeekRead, ReReadWithState};
+use fajt_lexer::error::ErrorKind;
 use fajt_lexer::token::{Token, TokenValue};
-use fajt_lexer::Lexer;
+use fajt_lexer::{literal, punct, Lexer, LexerState};
 use std::io::{Seek, SeekFrom};
 
 #[test]
@@ -16,6 +17,27 @@ fn first_on_line() {
     assert!(!tokens[3].first_on_line);
 }
 
+#[test]
+fn first_on_line_after_line_terminators() {
+    let input = "a\r\nb\rc\nd\u{2028}e\u{2029}f g";
+    let mut lexer = Lexer::new(input).unwrap();
+    let tokens = lexer.read_all().unwrap();
+
+    let first_on_line: Vec<bool> = tokens.iter().map(|t| t.first_on_line).collect();
+    assert_eq!(first_on_line, [true, true, true, true, true, true, false]);
+}
+
+#[test]
+fn crlf_line_terminators() {
+    let input = "a\r\n\r\nb";
+    let mut lexer = Lexer::new(input).unwrap();
+    let tokens = lexer.read_all().unwrap();
+
+    assert_eq!(tokens.len(), 2);
+    assert!(tokens[1].first_on_line);
+    assert_eq!(tokens[1].span, Span::new(5, 6));
+}
+
 #[test]
 fn seek_from_start() {
     let input = "ident1; ident2; ident3;";
@@ -29,8 +51,10 @@ fn seek_from_start() {
             14,
             Token {
                 span: Span::new(8, 14),
-                value: TokenValue::Identifier("ident2".to_string()),
+                value: TokenValue::Identifier("ident2".into()),
                 first_on_line: true,
+                legacy_octal: false,
+                escaped: false,
             }
         )
     );
@@ -49,8 +73,10 @@ fn seek_from_end() {
             22,
             Token {
                 span: Span::new(16, 22),
-                value: TokenValue::Identifier("ident3".to_string()),
+                value: TokenValue::Identifier("ident3".into()),
                 first_on_line: true,
+                legacy_octal: false,
+                escaped: false,
             }
         )
     );
@@ -71,9 +97,168 @@ fn seek_from_current() {
             6,
             Token {
                 span: Span::new(0, 6),
-                value: TokenValue::Identifier("ident1".to_string()),
+                value: TokenValue::Identifier("ident1".into()),
                 first_on_line: true,
+                legacy_octal: false,
+                escaped: false,
             }
         )
     );
 }
+
+#[test]
+fn get_and_set_state() {
+    let mut lexer = Lexer::new("a").unwrap();
+    assert_eq!(lexer.get_state(), LexerState::default());
+
+    lexer.set_state(LexerState::regex_allowed());
+    assert_eq!(lexer.get_state(), LexerState::regex_allowed());
+}
+
+#[test]
+fn reread_slash_as_regexp() {
+    let input = "a = b\n/c/g";
+    let mut lexer = Lexer::new(input).unwrap();
+
+    for _ in 0..3 {
+        lexer.next().unwrap();
+    }
+
+    let (_, slash) = lexer.next().unwrap();
+    assert_eq!(slash.value, punct!("/"));
+
+    lexer.rewind_before(&slash);
+    let (_, regexp) = lexer.read_with_state(LexerState::regex_allowed()).unwrap();
+    assert_eq!(
+        regexp,
+        Token {
+            span: Span::new(6, 10),
+            value: literal!(regexp, "c", "g"),
+            first_on_line: true,
+            legacy_octal: false,
+            escaped: false,
+        }
+    );
+
+    // The state is only used for the re-read token.
+    assert_eq!(lexer.get_state(), LexerState::default());
+    assert!(lexer.next().is_err());
+}
+
+#[test]
+fn read_all_with_regexp_state() {
+    let mut lexer = Lexer::new("/a/ /b/").unwrap();
+    lexer.set_state(LexerState::regex_allowed());
+
+    let tokens = lexer.read_all().unwrap();
+    let values: Vec<TokenValue> = tokens.into_iter().map(|t| t.value).collect();
+    assert_eq!(
+        values,
+        vec![literal!(regexp, "a", ""), literal!(regexp, "b", "")]
+    );
+}
+
+#[test]
+fn ascii_and_unicode_sources_are_read_the_same() {
+    let ascii = "var a = 'b'; // c\nd";
+    let unicode = "var a = 'b'; // \u{e5}\nd";
+
+    let mut ascii_lexer = Lexer::new(ascii).unwrap();
+    let mut unicode_lexer = Lexer::new(unicode).unwrap();
+    assert!(ascii_lexer.is_ascii());
+    assert!(!unicode_lexer.is_ascii());
+
+    let ascii_tokens = ascii_lexer.read_all().unwrap();
+    let unicode_tokens = unicode_lexer.read_all().unwrap();
+    assert_eq!(&ascii_tokens[..5], &unicode_tokens[..5]);
+
+    // The `å` is two bytes.
+    assert_eq!(ascii_tokens[5].span, Span::new(18, 19));
+    assert_eq!(unicode_tokens[5].span, Span::new(19, 20));
+}
+
+#[test]
+fn ascii_source_after_seek() {
+    let mut lexer = Lexer::new("a b c").unwrap();
+    lexer.seek(SeekFrom::Start(2)).unwrap();
+
+    let (_, token) = lexer.next().unwrap();
+    assert_eq!(token.value, TokenValue::Identifier("b".into()));
+    assert_eq!(token.span, Span::new(2, 3));
+}
+
+#[test]
+fn end_of_stream_error_by_default() {
+    let mut lexer = Lexer::new("a").unwrap();
+    lexer.read().unwrap();
+
+    let error = lexer.read().unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::EndOfStream);
+}
+
+#[test]
+fn eof_token() {
+    let input = "a // comment\n";
+    let mut lexer = Lexer::new(input).unwrap();
+    lexer.set_eof_token(true);
+
+    let tokens = lexer.read_all().unwrap();
+    assert_eq!(tokens.len(), 2);
+
+    let eof = &tokens[1];
+    assert_eq!(eof.value, TokenValue::Eof);
+    assert!(eof.value.is_eof());
+    assert!(eof.first_on_line);
+    assert_eq!(eof.span, Span::new(input.len(), input.len()));
+
+    // The Eof token is only read once.
+    assert_eq!(lexer.read().unwrap_err().kind(), &ErrorKind::EndOfStream);
+}
+
+#[test]
+fn eof_token_empty_source() {
+    let mut lexer = Lexer::new("").unwrap();
+    lexer.set_eof_token(true);
+
+    let token = lexer.read().unwrap();
+    assert_eq!(token.value, TokenValue::Eof);
+    assert_eq!(token.span, Span::new(0, 0));
+}
+
+#[test]
+fn eof_token_after_seek() {
+    let mut lexer = Lexer::new("a b").unwrap();
+    lexer.set_eof_token(true);
+    assert_eq!(lexer.read_all().unwrap().len(), 3);
+
+    lexer.seek(SeekFrom::Start(1)).unwrap();
+    let tokens = lexer.read_all().unwrap();
+    assert_eq!(tokens.len(), 2);
+    assert!(tokens[1].value.is_eof());
+}
+
+#[test]
+fn leading_byte_order_mark() {
+    let expected = Lexer::new("var x = 1;").unwrap().read_all().unwrap();
+
+    let mut lexer = Lexer::new("\u{feff}var x = 1;").unwrap();
+    assert!(lexer.is_ascii());
+    let tokens = lexer.read_all().unwrap();
+    let values = |tokens: &[Token]| tokens.iter().map(|t| t.value.clone()).collect::<Vec<_>>();
+    assert_eq!(values(&tokens), values(&expected));
+
+    // Spans are relative to the source including the byte order mark.
+    assert!(tokens[0].first_on_line);
+    assert_eq!(tokens[0].span, Span::new(3, 6));
+    assert_eq!(tokens.last().unwrap().span, Span::new(12, 13));
+}
+
+#[test]
+fn byte_order_mark_after_start_is_whitespace() {
+    let mut lexer = Lexer::new("a\u{feff}b").unwrap();
+    let tokens = lexer.read_all().unwrap();
+
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[1].span, Span::new(4, 5));
+    assert!(!tokens[1].first_on_line);
+}
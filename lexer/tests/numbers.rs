@@ -218,15 +218,26 @@ fn number_scientific_with_invalid_exponent() {
 }
 
 #[test]
-fn legacy_ocal_number_is_not_supported() {
-    assert_lexer!(
-        input: "01",
-        error: Error::syntax_error("Zero prefixed numbers are deprecated and not supported".to_owned(), (0, 1))
-    );
-    assert_lexer!(
-        input: "09",
-        error: Error::syntax_error("Zero prefixed numbers are deprecated and not supported".to_owned(), (0, 1))
-    );
+fn legacy_octal_number() {
+    let token = fajt_lexer::Lexer::new("0777").unwrap().read().unwrap();
+    assert_eq!(token.value, literal!(number, "0777"));
+    assert_eq!(token.span, (0, 4).into());
+    assert!(token.legacy_octal);
+}
+
+#[test]
+fn non_octal_decimal_number() {
+    let token = fajt_lexer::Lexer::new("089.5").unwrap().read().unwrap();
+    assert_eq!(token.value, literal!(number, "089.5"));
+    assert_eq!(token.span, (0, 5).into());
+    assert!(token.legacy_octal);
+}
+
+#[test]
+fn zero_is_not_legacy_octal() {
+    let token = fajt_lexer::Lexer::new("0.5").unwrap().read().unwrap();
+    assert_eq!(token.value, literal!(number, "0.5"));
+    assert!(!token.legacy_octal);
 }
 
 #[test]
@@ -4,7 +4,8 @@ use fajt_ast::Span;
 use fajt_lexer::error::Error;
 use fajt_lexer::literal;
 use fajt_lexer::punct;
-use fajt_lexer::token::Token;
+use fajt_lexer::token::{Punctuator, Token, TokenValue};
+use std::str::FromStr;
 
 #[test]
 fn parentheses() {
@@ -82,6 +83,71 @@ fn assignments() {
     );
 }
 
+#[test]
+fn arrow() {
+    assert_lexer!(
+        input: "a => b",
+        output: [
+            (identifier!("a"), (0, 1)),
+            (punct!("=>"), (2, 4)),
+            (identifier!("b"), (5, 6)),
+        ]
+    );
+}
+
+#[test]
+fn equality() {
+    assert_lexer!(
+        input: "a == b",
+        output: [
+            (identifier!("a"), (0, 1)),
+            (punct!("=="), (2, 4)),
+            (identifier!("b"), (5, 6)),
+        ]
+    );
+}
+
+#[test]
+fn strict_equality() {
+    assert_lexer!(
+        input: "a === b",
+        output: [
+            (identifier!("a"), (0, 1)),
+            (punct!("==="), (2, 5)),
+            (identifier!("b"), (6, 7)),
+        ]
+    );
+}
+
+#[test]
+fn equal_family_without_whitespace() {
+    assert_lexer!(
+        input: "a=b==c===d=>e",
+        output: [
+            (identifier!("a"), (0, 1)),
+            (punct!("="), (1, 2)),
+            (identifier!("b"), (2, 3)),
+            (punct!("=="), (3, 5)),
+            (identifier!("c"), (5, 6)),
+            (punct!("==="), (6, 9)),
+            (identifier!("d"), (9, 10)),
+            (punct!("=>"), (10, 12)),
+            (identifier!("e"), (12, 13)),
+        ]
+    );
+}
+
+#[test]
+fn equal_family_longest_match() {
+    assert_lexer!(
+        input: "====>",
+        output: [
+            (punct!("==="), (0, 3)),
+            (punct!("=>"), (3, 5)),
+        ]
+    );
+}
+
 #[test]
 fn bitwise() {
     assert_lexer!(
@@ -114,6 +180,22 @@ fn others() {
     );
 }
 
+#[test]
+fn logical_assignment() {
+    assert_lexer!(
+        input: "&&= ||= ??= &&== ??==",
+        output: [
+            (punct!("&&="), (0, 3)),
+            (punct!("||="), (4, 7)),
+            (punct!("??="), (8, 11)),
+            (punct!("&&="), (12, 15)),
+            (punct!("="), (15, 16)),
+            (punct!("??="), (17, 20)),
+            (punct!("="), (20, 21)),
+        ]
+    );
+}
+
 #[test]
 fn optional_chaining_exception() {
     assert_lexer!(
@@ -132,3 +214,112 @@ fn two_dots() {
         error: Error::invalid_or_unexpected_token(Token::new(punct!("."), true, Span::new(0, 1)))
     );
 }
+
+const PUNCTUATORS: [&str; 58] = [
+    "(", ")", "[", "]", "{", "}", ".", "...", ";", ",", "<", "<<", ">", ">>", ">>>", "=", "==",
+    "<=", "<<=", ">=", ">>=", ">>>=", "=>", "!=", "+=", "-=", "*=", "**=", "/=", "%=", "|=", "^=",
+    "&=", "===", "!==", "+", "++", "-", "--", "*", "**", "/", "%", "&", "&&", "&&=", "|", "||",
+    "||=", "^", "!", "~", "?", "??", "??=", "?.", ":", "@",
+];
+
+#[test]
+fn every_punctuator() {
+    let punctuators = PUNCTUATORS;
+
+    for punctuator in punctuators {
+        let mut lexer = fajt_lexer::Lexer::new(punctuator).unwrap();
+        let tokens = lexer.read_all().unwrap();
+
+        let expected = Punctuator::from_str(punctuator).unwrap();
+        assert_eq!(
+            tokens,
+            vec![Token::new(
+                TokenValue::Punctuator(expected),
+                true,
+                (0, punctuator.len())
+            )],
+            "Failed to lex `{punctuator}`"
+        );
+    }
+}
+
+#[test]
+fn longest_match() {
+    assert_lexer!(
+        input: ">>>>= <<<= &&& ||| ??? !=== +++ ---",
+        output: [
+            (punct!(">>>"), (0, 3)),
+            (punct!(">="), (3, 5)),
+            (punct!("<<"), (6, 8)),
+            (punct!("<="), (8, 10)),
+            (punct!("&&"), (11, 13)),
+            (punct!("&"), (13, 14)),
+            (punct!("||"), (15, 17)),
+            (punct!("|"), (17, 18)),
+            (punct!("??"), (19, 21)),
+            (punct!("?"), (21, 22)),
+            (punct!("!=="), (23, 26)),
+            (punct!("="), (26, 27)),
+            (punct!("++"), (28, 30)),
+            (punct!("+"), (30, 31)),
+            (punct!("--"), (32, 34)),
+            (punct!("-"), (34, 35)),
+        ]
+    );
+}
+
+#[test]
+fn relational_and_shift() {
+    assert_lexer!(
+        input: "a<b<<c>d>>e>>>f<=g>=h",
+        output: [
+            (identifier!("a"), (0, 1)),
+            (punct!("<"), (1, 2)),
+            (identifier!("b"), (2, 3)),
+            (punct!("<<"), (3, 5)),
+            (identifier!("c"), (5, 6)),
+            (punct!(">"), (6, 7)),
+            (identifier!("d"), (7, 8)),
+            (punct!(">>"), (8, 10)),
+            (identifier!("e"), (10, 11)),
+            (punct!(">>>"), (11, 14)),
+            (identifier!("f"), (14, 15)),
+            (punct!("<="), (15, 17)),
+            (identifier!("g"), (17, 18)),
+            (punct!(">="), (18, 20)),
+            (identifier!("h"), (20, 21)),
+        ]
+    );
+}
+
+#[test]
+fn optional_chaining_before_digit() {
+    assert_lexer!(
+        input: "a?.5:b",
+        output: [
+            (identifier!("a"), (0, 1)),
+            (punct!("?"), (1, 2)),
+            (literal!(number, ".5"), (2, 4)),
+            (punct!(":"), (4, 5)),
+            (identifier!("b"), (5, 6)),
+        ]
+    );
+}
+
+#[test]
+fn as_str_round_trip() {
+    for punctuator in PUNCTUATORS {
+        let parsed = Punctuator::from_str(punctuator).unwrap();
+        assert_eq!(parsed.as_str(), punctuator);
+        assert_eq!(Punctuator::from_str(parsed.as_str()), Ok(parsed));
+    }
+}
+
+#[test]
+fn variants() {
+    assert_eq!(Punctuator::VARIANTS.len(), PUNCTUATORS.len());
+    for (string, punctuator) in Punctuator::VARIANTS {
+        assert!(PUNCTUATORS.contains(string), "Missing `{string}`");
+        assert_eq!(punctuator.as_str(), *string);
+    }
+}
@@ -9,7 +9,7 @@ fn single_char() {
         state: LexerState::regex_allowed(),
         input: "/a/",
         output: [
-            (literal!(regexp, "/a/"), (0, 3)),
+            (literal!(regexp, "a", ""), (0, 3)),
         ]
     );
 }
@@ -20,7 +20,7 @@ fn multiple_chars() {
         state: LexerState::regex_allowed(),
         input: "/abcd/",
         output: [
-            (literal!(regexp, "/abcd/"), (0, 6)),
+            (literal!(regexp, "abcd", ""), (0, 6)),
         ]
     );
 }
@@ -31,7 +31,7 @@ fn escaped_backslash() {
         state: LexerState::regex_allowed(),
         input: r#"/a\\b/"#,
         output: [
-            (literal!(regexp, r#"/a\\b/"#), (0, 6)),
+            (literal!(regexp, r#"a\\b"#, ""), (0, 6)),
         ]
     );
 }
@@ -42,7 +42,7 @@ fn forward_slash_in_group() {
         state: LexerState::regex_allowed(),
         input: r#"/a[/]b/"#,
         output: [
-            (literal!(regexp, r#"/a[/]b/"#), (0, 7)),
+            (literal!(regexp, r#"a[/]b"#, ""), (0, 7)),
         ]
     );
 }
@@ -64,3 +64,30 @@ fn escaped_new_line_in_regexp() {
         error: Error::syntax_error("Unterminated regular expression literal".to_owned(), (0, 3))
     );
 }
+
+#[test]
+fn carriage_return_in_regexp() {
+    assert_lexer!(
+        state: LexerState::regex_allowed(),
+        input: "/a\rb/",
+        error: Error::syntax_error("Unterminated regular expression literal".to_owned(), (0, 2))
+    );
+}
+
+#[test]
+fn line_separator_in_regexp() {
+    assert_lexer!(
+        state: LexerState::regex_allowed(),
+        input: "/a\u{2028}b/",
+        error: Error::syntax_error("Unterminated regular expression literal".to_owned(), (0, 2))
+    );
+}
+
+#[test]
+fn new_line_in_regexp_group() {
+    assert_lexer!(
+        state: LexerState::regex_allowed(),
+        input: "/a[\r\n]/",
+        error: Error::syntax_error("Unterminated regular expression literal".to_owned(), (0, 3))
+    );
+}
@@ -47,7 +47,7 @@ fn single_quote_escaped() {
     assert_lexer!(
         input: r#"'a string \' \\ literal'"#,
         output: [
-            (literal!(string, '\'', r#"a string ' \ literal"#), (0, 24)),
+            (literal!(string, '\'', r#"a string ' \ literal"#, r#"a string \' \\ literal"#), (0, 24)),
         ]
     );
 }
@@ -57,7 +57,7 @@ fn double_quote_escaped() {
     assert_lexer!(
         input: r#""a string \" \\ literal""#,
         output: [
-            (literal!(string, '"', r#"a string " \ literal"#), (0, 24)),
+            (literal!(string, '"', r#"a string " \ literal"#, r#"a string \" \\ literal"#), (0, 24)),
         ]
     );
 }
@@ -67,7 +67,7 @@ fn escaped_new_line_in_string() {
     assert_lexer!(
         input: "\"Hello\\\nworld\"",
         output: [
-            (literal!(string, '"', "Hello\nworld"), (0, 14)),
+            (literal!(string, '"', "Helloworld", "Hello\\\nworld"), (0, 14)),
         ]
     );
 }
@@ -77,7 +77,7 @@ fn escaped_carriage_return_new_line_in_string() {
     assert_lexer!(
         input: "\"Hello\\\r\nworld\"",
         output: [
-            (literal!(string, '"', "Hello\r\nworld"), (0, 15)),
+            (literal!(string, '"', "Helloworld", "Hello\\\r\nworld"), (0, 15)),
         ]
     );
 }
@@ -117,3 +117,104 @@ fn unescaped_carriage_return_in_string() {
         error: Error::syntax_error("String contained unescaped new line".to_owned(), (6, 6))
     );
 }
+
+#[test]
+fn lone_surrogate_escape_in_string() {
+    assert_lexer!(
+        input: r#"'\uD800'"#,
+        output: [
+            (literal!(string, '\'', r#"\uD800"#), (0, 8)),
+        ]
+    );
+}
+
+#[test]
+fn code_point_escape_in_string() {
+    assert_lexer!(
+        input: r#""\u{10FFFF}""#,
+        output: [
+            (literal!(string, '"', "\u{10FFFF}", r#"\u{10FFFF}"#), (0, 12)),
+        ]
+    );
+}
+
+#[test]
+fn out_of_range_code_point_escape_in_string() {
+    assert_lexer!(
+        input: r#""\u{110000}""#,
+        error: Error::syntax_error("Invalid escape sequence".to_owned(), (1, 11))
+    );
+}
+
+#[test]
+fn invalid_unicode_escape_in_string() {
+    assert_lexer!(
+        input: r#""\u00G0""#,
+        error: Error::syntax_error("Invalid escape sequence".to_owned(), (1, 6))
+    );
+}
+
+#[test]
+fn legacy_octal_escape_in_string() {
+    let token = fajt_lexer::Lexer::new(r#""\012\48\9""#)
+        .unwrap()
+        .read()
+        .unwrap();
+    assert_eq!(token.value, literal!(string, '"', r#"\012\48\9"#));
+    assert!(token.legacy_octal);
+}
+
+#[test]
+fn null_escape_in_string_is_not_legacy_octal() {
+    let token = fajt_lexer::Lexer::new(r#""\0""#).unwrap().read().unwrap();
+    assert_eq!(token.value, literal!(string, '"', "\0", r#"\0"#));
+    assert!(!token.legacy_octal);
+}
+
+#[test]
+fn escaped_flag() {
+    let token = fajt_lexer::Lexer::new(r#""use\ strict""#)
+        .unwrap()
+        .read()
+        .unwrap();
+    assert_eq!(
+        token.value,
+        literal!(string, '"', "use strict", r#"use\ strict"#)
+    );
+    assert!(token.escaped);
+
+    let token = fajt_lexer::Lexer::new(r#""use strict""#)
+        .unwrap()
+        .read()
+        .unwrap();
+    assert!(!token.escaped);
+}
+
+#[test]
+fn raw_keeps_escape_sequences() {
+    let token = fajt_lexer::Lexer::new(r#""\n""#).unwrap().read().unwrap();
+    assert_eq!(token.value, literal!(string, '"', "\n", r#"\n"#));
+}
+
+#[test]
+fn escape_sequences_are_cooked() {
+    let token = fajt_lexer::Lexer::new(r#"'\b\f\n\r\t\v\0\x41\u0042\u{43}\'\"\\'"#)
+        .unwrap()
+        .read()
+        .unwrap();
+    assert_eq!(
+        token.value,
+        literal!(
+            string,
+            '\'',
+            "\u{8}\u{c}\n\r\t\u{b}\0ABC'\"\\",
+            r#"\b\f\n\r\t\v\0\x41\u0042\u{43}\'\"\\"#
+        )
+    );
+}
+
+#[test]
+fn invalid_hex_escape() {
+    let result = fajt_lexer::Lexer::new(r#""\x4""#).unwrap().read();
+    assert!(result.is_err());
+}
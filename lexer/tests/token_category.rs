@@ -0,0 +1,43 @@
+use fajt_lexer::token::TokenCategory;
+use fajt_lexer::Lexer;
+
+fn categories(input: &str) -> Vec<TokenCategory> {
+    let mut lexer = Lexer::new(input).unwrap();
+    let tokens = lexer.read_all().unwrap();
+    tokens.iter().map(|token| token.value.category()).collect()
+}
+
+#[test]
+fn category_of_each_kind() {
+    assert_eq!(
+        categories("let a = 'b' + 1 + `d` + `e${"),
+        [
+            TokenCategory::Keyword,
+            TokenCategory::Identifier,
+            TokenCategory::Punctuator,
+            TokenCategory::Literal,
+            TokenCategory::Punctuator,
+            TokenCategory::Literal,
+            TokenCategory::Punctuator,
+            TokenCategory::Literal,
+            TokenCategory::Punctuator,
+            TokenCategory::Template,
+        ]
+    );
+}
+
+#[test]
+fn category_predicates() {
+    let mut lexer = Lexer::new("if a ; 1 `b${").unwrap();
+    let tokens = lexer.read_all().unwrap();
+
+    assert!(tokens[0].value.is_keyword());
+    assert!(tokens[1].value.is_identifier());
+    assert!(tokens[2].value.is_punctuator());
+    assert!(tokens[3].value.is_literal());
+    assert!(tokens[4].value.is_template());
+
+    assert!(!tokens[0].value.is_identifier());
+    assert!(!tokens[1].value.is_keyword());
+    assert!(!tokens[3].value.is_punctuator());
+}
@@ -29,6 +29,11 @@ macro_rules! assert_lexer {
         let mut expected = vec![$(fajt_lexer::token::Token::new($token, false, ($col1, $col2))),*];
         expected[0].first_on_line = true;
 
+        // Flags like `escaped` and `legacy_octal` are tested separately.
+        let tokens: Vec<_> = tokens
+            .into_iter()
+            .map(|token| fajt_lexer::token::Token::new(token.value, token.first_on_line, token.span))
+            .collect();
         assert_eq!(tokens, expected);
     };
     ($(state: $state:expr,)? input: $input:expr, error: $error:expr) => {
@@ -48,6 +53,6 @@ macro_rules! assert_lexer {
 #[macro_export]
 macro_rules! identifier {
     ($name:expr) => {
-        fajt_lexer::token::TokenValue::Identifier($name.to_owned())
+        fajt_lexer::token::TokenValue::Identifier($name.into())
     };
 }
@@ -1,26 +1,47 @@
 use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::quote;
+use std::collections::BTreeMap;
 use syn::{Attribute, DataEnum, DeriveInput, Variant};
 
 pub fn enum_from_string(input: &DeriveInput, enum_data: &DataEnum) -> TokenStream {
     let from_str_impl_tokens = generate_from_str_impl(input, enum_data);
     let to_str_impl_tokens = generate_to_str_impl(input, enum_data);
     let macro_tokens = generate_macro(input, enum_data);
+    let variants_tokens = generate_variants(input, enum_data);
 
     quote! {
         #macro_tokens
         #from_str_impl_tokens
         #to_str_impl_tokens
+        #variants_tokens
     }
 }
 
 fn generate_from_str_impl(input: &DeriveInput, enum_data: &DataEnum) -> TokenStream {
     let ident = &input.ident;
-    let match_branches = map_variants(enum_data, |v| {
-        let variant_ident = &v.ident;
-        let variant_string = variant_string(v);
-        quote! {
+
+    // Variants are grouped by length and first byte, so a lookup is only compared against the few
+    // variants that could match instead of all of them.
+    let mut groups: BTreeMap<(usize, Option<u8>), Vec<TokenStream>> = BTreeMap::new();
+    for variant in &enum_data.variants {
+        let variant_ident = &variant.ident;
+        let variant_string = variant_string(variant);
+        let key = (variant_string.len(), variant_string.bytes().next());
+        groups.entry(key).or_default().push(quote! {
             #variant_string => Ok(#ident::#variant_ident)
+        });
+    }
+
+    let group_branches = groups.into_iter().map(|((length, first), branches)| {
+        let first = match first {
+            Some(byte) => quote! { Some(#byte) },
+            None => quote! { None },
+        };
+        quote! {
+            (#length, #first) => match s {
+                #(#branches,)*
+                _ => Err("No matching enum found."),
+            }
         }
     });
 
@@ -29,8 +50,8 @@ fn generate_from_str_impl(input: &DeriveInput, enum_data: &DataEnum) -> TokenStr
             type Err = &'static str;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                match s {
-                    #(#match_branches,)*
+                match (s.len(), s.bytes().next()) {
+                    #(#group_branches,)*
                     _ => Err("No matching enum found."),
                 }
             }
@@ -50,21 +71,47 @@ fn generate_to_str_impl(input: &DeriveInput, enum_data: &DataEnum) -> TokenStrea
 
     quote! {
         impl #ident {
-            pub fn as_str(&self) -> &str {
+            pub fn as_str(&self) -> &'static str {
                 match self {
                     #(#match_branches,)*
                 }
             }
         }
 
-        impl std::string::ToString for #ident {
-            fn to_string(&self) -> String {
-                self.as_str().to_string()
+        impl std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
             }
         }
     }
 }
 
+fn generate_variants(input: &DeriveInput, enum_data: &DataEnum) -> Option<TokenStream> {
+    let has_attribute = input
+        .attrs
+        .iter()
+        .any(|a| a.path.is_ident("from_string_variants"));
+    if !has_attribute {
+        return None;
+    }
+
+    let ident = &input.ident;
+    let entries = map_variants(enum_data, |v| {
+        let variant_ident = &v.ident;
+        let variant_string = variant_string(v);
+        quote! {
+            (#variant_string, #ident::#variant_ident)
+        }
+    });
+
+    Some(quote! {
+        impl #ident {
+            /// All variants together with their string representation, in declaration order.
+            pub const VARIANTS: &'static [(&'static str, Self)] = &[#(#entries,)*];
+        }
+    })
+}
+
 fn generate_macro(input: &DeriveInput, enum_data: &DataEnum) -> Option<TokenStream> {
     let macro_name = get_macro_name(&input.attrs);
     let extra_rules = get_macro_rules(&input.attrs).unwrap_or_else(|| {
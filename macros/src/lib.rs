@@ -4,13 +4,16 @@ extern crate syn;
 
 mod enum_from_string;
 mod for_each_file;
+mod pretty;
+mod structural;
 
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, Data, DeriveInput};
 
 use enum_from_string::enum_from_string;
 
-/// Generates FromStr implementation for enum.
+/// Generates FromStr implementation for enum, and the reverse `as_str` method and `Display`
+/// implementation from the same spellings, so both directions always match.
 /// It can also generate a macro for translating strings to enum variants.
 ///
 /// Example:
@@ -27,6 +30,8 @@ use enum_from_string::enum_from_string;
 /// // Runtime
 /// assert_eq!("horse".parse().unwrap(), Animal::Horse);
 /// assert_eq!("piggy".parse().unwrap(), Animal::Pig);
+/// assert_eq!(Animal::Pig.as_str(), "piggy");
+/// assert_eq!(Animal::Horse.to_string(), "horse");
 ///
 /// // Compile time
 /// assert_eq!(animal!("horse"), Animal::Horse);
@@ -57,9 +62,29 @@ use enum_from_string::enum_from_string;
 ///     Pig,
 /// }
 /// ```
+///
+/// # Variants
+/// `from_string_variants` generates `VARIANTS`, a slice of all variants and their strings.
+///
+/// Example usage:
+/// ```compile_fail
+/// #[derive(Eq, Debug, FromString)]
+/// #[from_string_variants]
+/// enum Animal {
+///     Horse,
+///     Cow,
+/// }
+///
+/// assert_eq!(Animal::VARIANTS, &[("horse", Animal::Horse), ("cow", Animal::Cow)]);
+/// ```
 #[proc_macro_derive(
     FromString,
-    attributes(from_string, from_string_macro, from_string_macro_rules)
+    attributes(
+        from_string,
+        from_string_macro,
+        from_string_macro_rules,
+        from_string_variants
+    )
 )]
 pub fn enum_from_string_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -75,6 +100,9 @@ pub fn enum_from_string_derive(input: TokenStream) -> TokenStream {
 
 /// This macro recursively iterates through a directory and calls a macro for each file.
 ///
+/// The identifier is the path relative to the directory without extension, `-` and `.` are
+/// replaced with `_` and directory separators with `__`, i.e. `nested/a-b.md` is `nested__a_b`.
+///
 /// Example, generating test cases from files:
 /// ```compile_fail
 /// macro_rules! generate_test_case {
@@ -95,3 +123,39 @@ pub fn enum_from_string_derive(input: TokenStream) -> TokenStream {
 pub fn for_each_file(input: TokenStream) -> TokenStream {
     for_each_file::for_each_file(input)
 }
+
+/// Implements `Structural` from `fajt_ast::structural`, i.e. equality and hashing ignoring spans,
+/// by comparing and hashing all fields with `Structural`. Type parameters must be `Structural`.
+///
+/// Note: Only for use within `fajt_ast`, the trait is referred to as `crate::structural`.
+///
+/// Example:
+/// ```compile_fail
+/// #[derive(Structural)]
+/// struct ExprThis {
+///     span: Span,
+/// }
+/// ```
+#[proc_macro_derive(Structural)]
+pub fn structural_derive(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(structural::structural(&mut input))
+}
+
+/// Implements `Pretty` from `fajt_ast::pretty`, i.e. the tree representation used by `Display`,
+/// from the names and values of all fields. Type parameters must be `Pretty`.
+///
+/// Note: Only for use within `fajt_ast`, the trait is referred to as `crate::pretty`.
+///
+/// Example:
+/// ```compile_fail
+/// #[derive(Pretty)]
+/// struct ExprThis {
+///     span: Span,
+/// }
+/// ```
+#[proc_macro_derive(Pretty)]
+pub fn pretty_derive(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(pretty::pretty(&mut input))
+}
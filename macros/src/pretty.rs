@@ -0,0 +1,94 @@
+use crate::structural::destructure;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Fields};
+
+pub fn pretty(input: &mut DeriveInput) -> TokenStream {
+    let name = &input.ident;
+
+    let type_params: Vec<_> = input
+        .generics
+        .type_params()
+        .map(|p| p.ident.clone())
+        .collect();
+    let where_clause = input.generics.make_where_clause();
+    for param in type_params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: crate::pretty::Pretty));
+    }
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let tree = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, bindings) = destructure(&data.fields, "a");
+            let tree = node(&name.to_string(), &data.fields, &bindings, false);
+            quote! {
+                let Self #pattern = self;
+                #tree
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                let (pattern, bindings) = destructure(&variant.fields, "a");
+                let tree = node(&variant_name.to_string(), &variant.fields, &bindings, true);
+                quote! {
+                    Self::#variant_name #pattern => { #tree }
+                }
+            });
+
+            quote! {
+                match self {
+                    #( #arms )*
+                }
+            }
+        }
+        Data::Union(_) => panic!("Pretty is not applicable for unions."),
+    };
+
+    quote! {
+        impl #impl_generics crate::pretty::Pretty for #name #type_generics #where_clause {
+            fn tree(&self) -> crate::pretty::Tree {
+                #tree
+            }
+        }
+    }
+}
+
+/// Returns the tree of a struct or enum variant named `kind`, with its fields bound to `bindings`.
+fn node(
+    kind: &str,
+    fields: &Fields,
+    bindings: &[proc_macro2::Ident],
+    is_variant: bool,
+) -> TokenStream {
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields
+                .named
+                .iter()
+                .map(|f| f.ident.as_ref().unwrap().to_string());
+            quote! {
+                crate::pretty::Tree::Node {
+                    kind: #kind,
+                    fields: vec![ #( (#names, crate::pretty::Pretty::tree(#bindings)) ),* ],
+                    is_variant: #is_variant,
+                }
+            }
+        }
+        Fields::Unnamed(_) if bindings.len() == 1 => {
+            let binding = &bindings[0];
+            quote! {
+                crate::pretty::Pretty::tree(#binding).with_kind(#kind)
+            }
+        }
+        Fields::Unnamed(_) => quote! {
+            crate::pretty::Tree::Seq(vec![ #( crate::pretty::Pretty::tree(#bindings) ),* ])
+                .with_kind(#kind)
+        },
+        Fields::Unit => quote! {
+            crate::pretty::Tree::Scalar(#kind.to_owned())
+        },
+    }
+}
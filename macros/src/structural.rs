@@ -0,0 +1,111 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DeriveInput, Fields};
+
+pub fn structural(input: &mut DeriveInput) -> TokenStream {
+    let name = &input.ident;
+
+    let type_params: Vec<_> = input
+        .generics
+        .type_params()
+        .map(|p| p.ident.clone())
+        .collect();
+    let where_clause = input.generics.make_where_clause();
+    for param in type_params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: crate::structural::Structural));
+    }
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let (eq, hash) = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, self_fields) = destructure(&data.fields, "a");
+            let (other_pattern, other_fields) = destructure(&data.fields, "b");
+            (
+                quote! {
+                    let Self #pattern = self;
+                    let Self #other_pattern = other;
+                    true #( && #self_fields.structural_eq(#other_fields) )*
+                },
+                quote! {
+                    let Self #pattern = self;
+                    #( #self_fields.structural_hash(state); )*
+                },
+            )
+        }
+        Data::Enum(data) => {
+            let mut eq_arms = Vec::new();
+            let mut hash_arms = Vec::new();
+            for variant in &data.variants {
+                let variant_name = &variant.ident;
+                let (pattern, self_fields) = destructure(&variant.fields, "a");
+                let (other_pattern, other_fields) = destructure(&variant.fields, "b");
+                eq_arms.push(quote! {
+                    (Self::#variant_name #pattern, Self::#variant_name #other_pattern) => {
+                        true #( && #self_fields.structural_eq(#other_fields) )*
+                    }
+                });
+                hash_arms.push(quote! {
+                    Self::#variant_name #pattern => {
+                        #( #self_fields.structural_hash(state); )*
+                    }
+                });
+            }
+
+            (
+                quote! {
+                    #[allow(unreachable_patterns)]
+                    match (self, other) {
+                        #( #eq_arms )*
+                        _ => false,
+                    }
+                },
+                quote! {
+                    std::hash::Hash::hash(&std::mem::discriminant(self), state);
+                    match self {
+                        #( #hash_arms )*
+                    }
+                },
+            )
+        }
+        Data::Union(_) => panic!("Structural is not applicable for unions."),
+    };
+
+    quote! {
+        impl #impl_generics crate::structural::Structural for #name #type_generics #where_clause {
+            fn structural_eq(&self, other: &Self) -> bool {
+                #eq
+            }
+
+            fn structural_hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                #hash
+            }
+        }
+    }
+}
+
+/// Returns a pattern binding all `fields` to variables starting with `prefix`, and the variables.
+pub fn destructure(fields: &Fields, prefix: &str) -> (TokenStream, Vec<Ident>) {
+    match fields {
+        Fields::Named(fields) => {
+            let names: Vec<_> = fields
+                .named
+                .iter()
+                .map(|f| f.ident.clone().unwrap())
+                .collect();
+            let bindings: Vec<_> = names
+                .iter()
+                .map(|name| format_ident!("{}_{}", prefix, name))
+                .collect();
+            (quote! { { #( #names: #bindings ),* } }, bindings)
+        }
+        Fields::Unnamed(fields) => {
+            let bindings: Vec<_> = (0..fields.unnamed.len())
+                .map(|i| format_ident!("{}_{}", prefix, i))
+                .collect();
+            (quote! { ( #( #bindings ),* ) }, bindings)
+        }
+        Fields::Unit => (quote! {}, Vec::new()),
+    }
+}
@@ -0,0 +1,30 @@
+use fajt_macros::for_each_file;
+
+/// Generates a function, named after the file, that returns the extension and path of the file.
+macro_rules! file_fn {
+    ($extension:literal, $file_path:literal, $ident:ident) => {
+        fn $ident() -> (&'static str, &'static str) {
+            ($extension, $file_path)
+        }
+    };
+}
+
+for_each_file!("macros/tests/for_each_file", file_fn);
+
+#[test]
+fn top_level_file() {
+    let (extension, path) = top_level();
+    assert_eq!(extension, "txt");
+    assert!(path.ends_with("for_each_file/top-level.txt"));
+}
+
+#[test]
+fn nested_files() {
+    let (extension, path) = nested__in_nested();
+    assert_eq!(extension, "txt");
+    assert!(path.ends_with("for_each_file/nested/in-nested.txt"));
+
+    let (extension, path) = nested__deeper__in_deeper();
+    assert_eq!(extension, "md");
+    assert!(path.ends_with("for_each_file/nested/deeper/in-deeper.md"));
+}
@@ -0,0 +1,63 @@
+use fajt_macros::FromString;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, FromString)]
+#[from_string_macro("animal")]
+#[from_string_variants]
+#[from_string_macro_rules(
+    ($variant:ident) => {
+        Animal::$variant
+    };
+)]
+enum Animal {
+    Horse,
+    SeaLion,
+    #[from_string("piggy")]
+    Pig,
+}
+
+const ANIMALS: [Animal; 3] = [Animal::Horse, Animal::SeaLion, Animal::Pig];
+
+#[test]
+fn from_str() {
+    assert_eq!(Animal::from_str("horse"), Ok(Animal::Horse));
+    assert_eq!(Animal::from_str("sealion"), Ok(Animal::SeaLion));
+    assert_eq!(Animal::from_str("piggy"), Ok(Animal::Pig));
+    assert!(Animal::from_str("pig").is_err());
+    assert!(Animal::from_str("horses").is_err());
+    assert!(Animal::from_str("hoRse").is_err());
+    assert!(Animal::from_str("").is_err());
+}
+
+#[test]
+fn as_str() {
+    assert_eq!(Animal::Horse.as_str(), "horse");
+    assert_eq!(Animal::SeaLion.as_str(), "sealion");
+    assert_eq!(Animal::Pig.as_str(), "piggy");
+    assert_eq!(Animal::Pig.to_string(), "piggy");
+}
+
+#[test]
+fn round_trip() {
+    for animal in ANIMALS {
+        assert_eq!(Animal::from_str(animal.as_str()), Ok(animal));
+    }
+}
+
+#[test]
+fn macro_rules() {
+    assert_eq!(animal!("horse"), Animal::Horse);
+    assert_eq!(animal!("piggy"), Animal::Pig);
+}
+
+#[test]
+fn variants() {
+    assert_eq!(
+        Animal::VARIANTS,
+        &[
+            ("horse", Animal::Horse),
+            ("sealion", Animal::SeaLion),
+            ("piggy", Animal::Pig)
+        ]
+    );
+}
@@ -0,0 +1,99 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use fajt_ast::{Program, SourceType};
+use fajt_lexer::Lexer;
+use fajt_parser::{parse_program, parse_with_options, ParseOptions};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocator counting the number of allocations, to compare parsing with and without interning.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(f());
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+const CHUNK: &str = r#"
+async function load(urls, { retries = 3, ...options } = {}) {
+    const results = [];
+    for (const url of urls) {
+        try {
+            results.push(await fetch(url, options));
+        } catch (error) {
+            if (retries-- > 0) continue;
+            throw new Error(`Failed to load ${url}`);
+        }
+    }
+    return results.filter((result) => result?.ok ?? false);
+}
+
+class Queue extends Array {
+    static of(...items) {
+        return new Queue(...items);
+    }
+
+    *drain() {
+        while (this.length) yield this.shift();
+    }
+}
+
+label: for (var i = 0, j = 10; i < j; i++, j--) {
+    switch (i % 3) {
+        case 0: break label;
+        default: void typeof i === "number" ? i : -i;
+    }
+}
+"#;
+
+fn parser_benchmark(c: &mut Criterion) {
+    let source = CHUNK.repeat(1_000_000 / CHUNK.len());
+    assert!(Lexer::new(&source).unwrap().is_ascii());
+
+    let mut group = c.benchmark_group("parser");
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    group.sample_size(20);
+    group.bench_function("program", |b| {
+        b.iter(|| parse_program(black_box(&source)).unwrap())
+    });
+    group.finish();
+}
+
+fn interning_benchmark(c: &mut Criterion) {
+    let source = CHUNK.repeat(1_000_000 / CHUNK.len());
+    let options = ParseOptions::new(SourceType::Script);
+    let interned_options = options.with_interned_identifiers(true);
+
+    let parse = |options: &ParseOptions| parse_with_options::<Program>(&source, options).unwrap();
+    let plain_allocations = count_allocations(|| parse(&options));
+    let interned_allocations = count_allocations(|| parse(&interned_options));
+    println!("allocations: {plain_allocations} plain, {interned_allocations} interned");
+    assert!(interned_allocations < plain_allocations);
+
+    let mut group = c.benchmark_group("parser");
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    group.sample_size(20);
+    group.bench_function("program-interned", |b| {
+        b.iter(|| parse(black_box(&interned_options)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parser_benchmark, interning_benchmark);
+criterion_main!(benches);
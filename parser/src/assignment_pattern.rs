@@ -20,6 +20,10 @@ where
     I: ReReadWithState<Token, State = LexerState, Error = fajt_lexer::error::Error>,
 {
     pub(super) fn parse_assignment_pattern(&mut self) -> Result<AssignmentPattern> {
+        self.nested(Self::parse_nested_assignment_pattern)
+    }
+
+    fn parse_nested_assignment_pattern(&mut self) -> Result<AssignmentPattern> {
         match self.current()? {
             token_matches!(punct!("[")) => self.parse_array_assignment_pattern(),
             token_matches!(punct!("{")) => self.parse_object_assignment_pattern(),
@@ -44,6 +48,13 @@ where
                     self.consume()?;
                     let rest_expr = self.parse_destructuring_assignment_target()?;
 
+                    if self.current_matches(&punct!("=")) {
+                        return Err(Error::syntax_error(
+                            "Rest element may not have a default initializer".to_owned(),
+                            rest_expr.span().clone(),
+                        ));
+                    }
+
                     if !self.maybe_consume(&punct!("]"))? {
                         return Err(Error::syntax_error(
                             "Rest element must be last element".to_owned(),
@@ -60,6 +71,7 @@ where
                 }
                 _ => {
                     elements.push(Some(self.parse_assignment_element()?));
+                    self.consume_list_delimiter(&punct!("]"))?;
                 }
             }
         }
@@ -185,7 +197,7 @@ where
     fn parse_single_name_assignment_prop(&mut self) -> Result<SingleNameAssignmentProp> {
         let span_start = self.position();
 
-        let ident = self.parse_identifier()?;
+        let ident = self.parse_binding_identifier()?;
         let initializer = self
             .current_matches(&punct!("="))
             .then_try(|| self.parse_initializer())?;
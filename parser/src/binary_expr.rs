@@ -1,14 +1,69 @@
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::Parser;
 use fajt_ast::binary_op;
 use fajt_ast::logical_op;
-use fajt_ast::{BinaryOperator, Expr, ExprBinary, ExprLogical, LogicalOperator, Span};
+use fajt_ast::{
+    Associativity, BinaryOperator, Expr, ExprBinary, ExprLogical, LogicalOperator, Spanned,
+};
 use fajt_common::io::{PeekRead, ReReadWithState};
 use fajt_lexer::punct;
 use fajt_lexer::token::Token;
 use fajt_lexer::token_matches;
 use fajt_lexer::{keyword, LexerState};
 
+/// Operator of a binary or logical expression.
+enum Operator {
+    Binary(BinaryOperator),
+    Logical(LogicalOperator),
+}
+
+impl Operator {
+    fn binding_power(&self) -> (u8, Associativity) {
+        match self {
+            Operator::Binary(operator) => operator.binding_power(),
+            Operator::Logical(operator) => operator.binding_power(),
+        }
+    }
+}
+
+/// Maps `token` to a binary or logical operator together with its binding power and
+/// associativity. This is the single source of precedence for all binary and logical expressions.
+///
+/// `in_allowed` is the `In` production parameter, `in` is not an operator without it.
+fn binding_power(token: &Token, in_allowed: bool) -> Option<(Operator, u8, Associativity)> {
+    let operator = match token {
+        token_matches!(punct!("??")) => Operator::Logical(logical_op!("??")),
+        token_matches!(punct!("||")) => Operator::Logical(logical_op!("||")),
+        token_matches!(punct!("&&")) => Operator::Logical(logical_op!("&&")),
+        token_matches!(punct!("|")) => Operator::Binary(binary_op!("|")),
+        token_matches!(punct!("^")) => Operator::Binary(binary_op!("^")),
+        token_matches!(punct!("&")) => Operator::Binary(binary_op!("&")),
+        token_matches!(punct!("==")) => Operator::Binary(binary_op!("==")),
+        token_matches!(punct!("!=")) => Operator::Binary(binary_op!("!=")),
+        token_matches!(punct!("===")) => Operator::Binary(binary_op!("===")),
+        token_matches!(punct!("!==")) => Operator::Binary(binary_op!("!==")),
+        token_matches!(punct!("<")) => Operator::Binary(binary_op!("<")),
+        token_matches!(punct!(">")) => Operator::Binary(binary_op!(">")),
+        token_matches!(punct!("<=")) => Operator::Binary(binary_op!("<=")),
+        token_matches!(punct!(">=")) => Operator::Binary(binary_op!(">=")),
+        token_matches!(keyword!("instanceof")) => Operator::Binary(binary_op!("instanceof")),
+        token_matches!(keyword!("in")) if in_allowed => Operator::Binary(binary_op!("in")),
+        token_matches!(punct!("<<")) => Operator::Binary(binary_op!("<<")),
+        token_matches!(punct!(">>")) => Operator::Binary(binary_op!(">>")),
+        token_matches!(punct!(">>>")) => Operator::Binary(binary_op!(">>>")),
+        token_matches!(punct!("+")) => Operator::Binary(binary_op!("+")),
+        token_matches!(punct!("-")) => Operator::Binary(binary_op!("-")),
+        token_matches!(punct!("*")) => Operator::Binary(binary_op!("*")),
+        token_matches!(punct!("/")) => Operator::Binary(binary_op!("/")),
+        token_matches!(punct!("%")) => Operator::Binary(binary_op!("%")),
+        token_matches!(punct!("**")) => Operator::Binary(binary_op!("**")),
+        _ => return None,
+    };
+
+    let (power, associativity) = operator.binding_power();
+    Some((operator, power, associativity))
+}
+
 impl<I> Parser<'_, I>
 where
     I: PeekRead<Token, Error = fajt_lexer::error::Error>,
@@ -16,208 +71,88 @@ where
 {
     /// Parses the `ShortCircuitExpression` production.
     pub(super) fn parse_short_circuit_expr(&mut self) -> Result<Expr> {
-        self.parse_logical_expr(Self::parse_logical_or_expr, |token| {
-            if token_matches!(token, punct!("??")) {
-                Some(logical_op!("??"))
-            } else {
-                None
-            }
-        })
-    }
-
-    /// Parses the `LogicalORExpression` production.
-    fn parse_logical_or_expr(&mut self) -> Result<Expr> {
-        self.parse_logical_expr(Self::parse_logical_and_expr, |token| {
-            if token_matches!(token, punct!("||")) {
-                Some(logical_op!("||"))
-            } else {
-                None
-            }
-        })
+        self.parse_binary_expr(0)
     }
 
-    /// Parses the `LogicalANDExpression` production.
-    fn parse_logical_and_expr(&mut self) -> Result<Expr> {
-        self.parse_logical_expr(Self::parse_bitwise_or_expr, |token| {
-            if token_matches!(token, punct!("&&")) {
-                Some(logical_op!("&&"))
-            } else {
-                None
-            }
-        })
-    }
-
-    /// Parses the `BitwiseORExpression` production.
-    fn parse_bitwise_or_expr(&mut self) -> Result<Expr> {
-        self.parse_binary_expr(Self::parse_bitwise_xor_expr, |token| {
-            if token_matches!(token, punct!("|")) {
-                Some(binary_op!("|"))
-            } else {
-                None
-            }
-        })
-    }
-
-    /// Parses the `BitwiseXORExpression` production.
-    fn parse_bitwise_xor_expr(&mut self) -> Result<Expr> {
-        self.parse_binary_expr(Self::parse_bitwise_and_expr, |token| {
-            if token_matches!(token, punct!("^")) {
-                Some(binary_op!("^"))
-            } else {
-                None
-            }
-        })
-    }
-
-    /// Parses the `BitwiseANDExpression` production.
-    fn parse_bitwise_and_expr(&mut self) -> Result<Expr> {
-        self.parse_binary_expr(Self::parse_equality_expr, |token| {
-            if token_matches!(token, punct!("&")) {
-                Some(binary_op!("&"))
-            } else {
-                None
-            }
-        })
-    }
-
-    /// Parses the `EqualityExpression` production.
-    fn parse_equality_expr(&mut self) -> Result<Expr> {
-        self.parse_binary_expr(Self::parse_relational_expr, |token| match token {
-            token_matches!(punct!("==")) => Some(binary_op!("==")),
-            token_matches!(punct!("!=")) => Some(binary_op!("!=")),
-            token_matches!(punct!("===")) => Some(binary_op!("===")),
-            token_matches!(punct!("!==")) => Some(binary_op!("!==")),
-            _ => None,
-        })
-    }
-
-    /// Parses the `RelationalExpression` production.
-    fn parse_relational_expr(&mut self) -> Result<Expr> {
-        let in_keyword_allowed = self.context.is_in;
-        self.parse_binary_expr(Self::parse_shift_expr, |token| match token {
-            token_matches!(punct!("<")) => Some(binary_op!("<")),
-            token_matches!(punct!(">")) => Some(binary_op!(">")),
-            token_matches!(punct!("<=")) => Some(binary_op!("<=")),
-            token_matches!(punct!(">=")) => Some(binary_op!(">=")),
-            token_matches!(keyword!("instanceof")) => Some(binary_op!("instanceof")),
-            token_matches!(keyword!("in")) if in_keyword_allowed => Some(binary_op!("in")),
-            _ => None,
-        })
-    }
-
-    /// Parses the `ShiftExpression` production.
-    fn parse_shift_expr(&mut self) -> Result<Expr> {
-        self.parse_binary_expr(Self::parse_additive_expr, |token| match token {
-            token_matches!(punct!("<<")) => Some(binary_op!("<<")),
-            token_matches!(punct!(">>")) => Some(binary_op!(">>")),
-            token_matches!(punct!(">>>")) => Some(binary_op!(">>>")),
-            _ => None,
-        })
-    }
-
-    /// Parses the `AdditiveExpression` production.
-    fn parse_additive_expr(&mut self) -> Result<Expr> {
-        self.parse_binary_expr(Self::parse_multiplicative_expr, |token| match token {
-            token_matches!(punct!("+")) => Some(binary_op!("+")),
-            token_matches!(punct!("-")) => Some(binary_op!("-")),
-            _ => None,
-        })
-    }
-
-    /// Parses the `MultiplicativeExpression` production.
-    fn parse_multiplicative_expr(&mut self) -> Result<Expr> {
-        self.parse_binary_expr(Self::parse_exponentiation_expr, |token| match token {
-            token_matches!(punct!("*")) => Some(binary_op!("*")),
-            token_matches!(punct!("/")) => Some(binary_op!("/")),
-            token_matches!(punct!("%")) => Some(binary_op!("%")),
-            _ => None,
-        })
-    }
-
-    /// Parses the `ExponentiationExpression` production.
-    fn parse_exponentiation_expr(&mut self) -> Result<Expr> {
-        self.parse_binary_expr(Self::parse_unary_expr, |token| {
-            if token_matches!(token, punct!("**")) {
-                Some(binary_op!("**"))
-            } else {
-                None
-            }
-        })
-    }
-
-    /// All binary expressions are parsed the same way, they are broken up into multiple productions
-    /// for precedence. This is the common parse method for all of them.
+    /// The specification has one production per precedence level, from `ShortCircuitExpression`
+    /// down to `ExponentiationExpression`. These are all parsed here, using `binding_power` to
+    /// decide how operators are nested.
     ///
-    /// `next` is a method for retrieving the result of the _next_ production (i.e. right hand).
-    /// `map_operator` is a function for mapping a token to a binary operator.
-    #[inline]
-    fn parse_binary_expr<F>(
-        &mut self,
-        next: fn(&mut Self) -> Result<Expr>,
-        map_operator: F,
-    ) -> Result<Expr>
-    where
-        F: Fn(&Token) -> Option<BinaryOperator>,
-    {
-        self.parse_recursive_binary_expr(next, map_operator, |span, left, right, operator| {
-            ExprBinary {
-                span,
-                left: Box::new(left),
-                right: Box::new(right),
-                operator,
-            }
-            .into()
-        })
-    }
-
-    /// All logical expressions are parsed the same way, they are broken up into multiple productions
-    /// for precedence. This is the common parse method for all of them.
-    ///
-    /// `next` is a method for retrieving the result of the _next_ production (i.e. right hand).
-    /// `map_operator` is a function for mapping a token to a binary operator.
-    #[inline]
-    fn parse_logical_expr(
-        &mut self,
-        next: fn(&mut Self) -> Result<Expr>,
-        map_operator: fn(&Token) -> Option<LogicalOperator>,
-    ) -> Result<Expr> {
-        self.parse_recursive_binary_expr(next, map_operator, |span, left, right, operator| {
-            ExprLogical {
-                span,
-                left: Box::new(left),
-                right: Box::new(right),
-                operator,
-            }
-            .into()
-        })
-    }
-
-    #[inline]
-    fn parse_recursive_binary_expr<T, F>(
-        &mut self,
-        next: fn(&mut Self) -> Result<Expr>,
-        map_operator: F,
-        create_expr: fn(span: Span, left: Expr, right: Expr, operator: T) -> Expr,
-    ) -> Result<Expr>
-    where
-        F: Fn(&Token) -> Option<T>,
-    {
+    /// Only operators with a binding power greater than `min_power` are consumed.
+    fn parse_binary_expr(&mut self, min_power: u8) -> Result<Expr> {
         let span_start = self.position();
-        let mut expr = next(self)?;
-        loop {
-            let operator = self.current().map(&map_operator);
-
-            if let Ok(Some(operator)) = operator {
-                self.consume()?;
-                let right = next(self)?;
-                let span = self.span_from(span_start);
+        let mut expr = self.parse_unary_expr()?;
 
-                expr = create_expr(span, expr, right, operator);
-            } else {
+        while let Ok(Some((operator, power, associativity))) = self
+            .current()
+            .map(|token| binding_power(token, self.context.is_in))
+        {
+            if power <= min_power {
                 break;
             }
+
+            let operator_token = self.consume()?;
+            // Left associative operators nest at most once per precedence level, while right
+            // associative operators nest once per operator, `a ** b ** c` is `a ** (b ** c)`.
+            let left = expr;
+            let right = match associativity {
+                Associativity::Left => self.parse_binary_expr(power)?,
+                Associativity::Right => {
+                    self.nested(|parser| parser.parse_binary_expr(power - 1))?
+                }
+            };
+            let span = self.span_from(span_start);
+
+            expr = match operator {
+                Operator::Binary(operator) => {
+                    if operator == binary_op!("**")
+                        && matches!(left, Expr::Unary(_) | Expr::Await(_))
+                    {
+                        return Err(Error::syntax_error(
+                            "Unary operator used immediately before exponentiation expression, parentheses must be used to disambiguate operator precedence".to_owned(),
+                            left.span().clone(),
+                        ));
+                    }
+
+                    ExprBinary {
+                        span,
+                        left: Box::new(left),
+                        right: Box::new(right),
+                        operator,
+                    }
+                    .into()
+                }
+                Operator::Logical(operator) => {
+                    if is_mixed_coalesce(&operator, &left) || is_mixed_coalesce(&operator, &right) {
+                        return Err(Error::syntax_error(
+                            "Cannot mix `??` with `&&` or `||` without parentheses".to_owned(),
+                            operator_token.span,
+                        ));
+                    }
+
+                    ExprLogical {
+                        span,
+                        left: Box::new(left),
+                        right: Box::new(right),
+                        operator,
+                    }
+                    .into()
+                }
+            };
         }
 
         Ok(expr)
     }
 }
+
+/// Returns `true` if `operand` is an unparenthesized logical expression that can not be an operand
+/// of `operator`, i.e. `??` mixed with `&&` or `||`.
+fn is_mixed_coalesce(operator: &LogicalOperator, operand: &Expr) -> bool {
+    if let Expr::Logical(operand) = operand {
+        let is_coalesce = operand.operator == logical_op!("??");
+        let operator_is_coalesce = *operator == logical_op!("??");
+        is_coalesce != operator_is_coalesce
+    } else {
+        false
+    }
+}
@@ -2,7 +2,7 @@ use crate::error::Result;
 use crate::{Error, Parser, ThenTry};
 use fajt_ast::{
     ArrayBinding, BindingElement, BindingPattern, NamedBinding, ObjectBinding, ObjectBindingProp,
-    SingleNameBinding, Span, Spanned,
+    SingleNameBinding, Spanned,
 };
 use fajt_common::io::{PeekRead, ReReadWithState};
 use fajt_lexer::token::Punctuator::{BraceClose, BracketClose};
@@ -17,6 +17,10 @@ where
 {
     /// Parses the `BindingPattern` production.
     pub(super) fn parse_binding_pattern(&mut self) -> Result<BindingPattern> {
+        self.nested(Self::parse_nested_binding_pattern)
+    }
+
+    fn parse_nested_binding_pattern(&mut self) -> Result<BindingPattern> {
         Ok(match self.current()? {
             token_matches!(punct!("{")) => {
                 BindingPattern::Object(self.parse_object_binding_pattern()?)
@@ -24,7 +28,7 @@ where
             token_matches!(punct!("[")) => {
                 BindingPattern::Array(self.parse_array_binding_pattern()?)
             }
-            _ => BindingPattern::Ident(self.parse_identifier()?),
+            _ => BindingPattern::Ident(self.parse_binding_identifier()?),
         })
     }
 
@@ -65,7 +69,7 @@ where
     fn parse_single_name_binding(&mut self) -> Result<SingleNameBinding> {
         let span_start = self.position();
 
-        let ident = self.parse_identifier()?;
+        let ident = self.parse_binding_identifier()?;
         let initializer = self.current_matches(&punct!("=")).then_try(|| {
             self.with_context(self.context.with_in(true))
                 .parse_initializer()
@@ -181,7 +185,7 @@ where
             }
         }
 
-        let span = Span::new(dots.span.start, pattern.span().end);
+        let span = dots.span.merge(pattern.span());
         Err(Error::syntax_error(
             "Rest element must be last element".to_owned(),
             span,
@@ -1,6 +1,6 @@
 use crate::error::Result;
-use crate::{Parser, ThenTry};
-use fajt_ast::{ClassElement, DeclClass, Expr, ExprClass, Ident, Stmt};
+use crate::{Error, Parser, ThenTry};
+use fajt_ast::{ClassElement, DeclClass, Decorator, Expr, ExprClass, Ident, Stmt};
 use fajt_common::io::{PeekRead, ReReadWithState};
 use fajt_lexer::punct;
 use fajt_lexer::token::Token;
@@ -14,6 +14,7 @@ where
     /// Parses the `ClassDeclaration` production.
     pub(super) fn parse_class_decl(&mut self) -> Result<Stmt> {
         let span_start = self.position();
+        let decorators = self.parse_decorators()?;
         self.consume_assert(&keyword!("class"))?;
 
         let identifier = self.parse_optional_class_identifier()?;
@@ -22,6 +23,7 @@ where
         let span = self.span_from(span_start);
         Ok(DeclClass {
             span,
+            decorators,
             identifier,
             super_class,
             body,
@@ -42,6 +44,7 @@ where
     /// Parses the `ClassExpression` production.
     pub(super) fn parse_class_expr(&mut self) -> Result<Expr> {
         let span_start = self.position();
+        let decorators = self.parse_decorators()?;
         self.consume_assert(&keyword!("class"))?;
 
         let identifier = self
@@ -52,6 +55,7 @@ where
         let span = self.span_from(span_start);
         Ok(ExprClass {
             span,
+            decorators,
             identifier,
             super_class,
             body,
@@ -72,6 +76,10 @@ where
 
     /// Parses the `ClassBody` production, including the { and } terminals.
     fn parse_class_body(&mut self, has_super: bool) -> Result<Vec<ClassElement>> {
+        self.nested(|parser| parser.parse_nested_class_body(has_super))
+    }
+
+    fn parse_nested_class_body(&mut self, has_super: bool) -> Result<Vec<ClassElement>> {
         self.consume_assert(&punct!("{"))?;
         let mut class_body = Vec::new();
 
@@ -94,14 +102,78 @@ where
 
     /// Parses the `ClassElement` production.
     fn parse_class_element(&mut self, has_super: bool) -> Result<ClassElement> {
-        let super_call_allowed =
-            has_super && self.current_matches_identifier_or_literal("constructor");
+        let decorators = self.parse_decorators()?;
+
+        let is_constructor = self.current_matches_identifier_or_literal("constructor");
+        if is_constructor && !decorators.is_empty() {
+            return Err(Error::syntax_error(
+                "Decorators are not valid on constructors".to_owned(),
+                decorators[0].span.clone(),
+            ));
+        }
+
         let context = self
             .context
             .with_in_method(true)
             .with_static_method_allowed(true)
-            .with_super_call_allowed(super_call_allowed);
+            .with_super_call_allowed(has_super && is_constructor);
+
+        let mut method = self.with_context(context).parse_method_definition()?;
+        if let Some(decorator) = decorators.first() {
+            method.span = decorator.span.merge(&method.span);
+        }
+
+        method.decorators = decorators;
+        Ok(method.into())
+    }
+
+    /// Parses the decorators preceding a class or class element, from the decorators proposal.
+    #[cfg(feature = "decorators")]
+    fn parse_decorators(&mut self) -> Result<Vec<Decorator>> {
+        let mut decorators = Vec::new();
+        while self.current_matches(&punct!("@")) {
+            decorators.push(self.parse_decorator()?);
+        }
+
+        Ok(decorators)
+    }
 
-        Ok(self.with_context(context).parse_method_definition()?.into())
+    #[cfg(not(feature = "decorators"))]
+    fn parse_decorators(&mut self) -> Result<Vec<Decorator>> {
+        Ok(Vec::new())
+    }
+
+    /// Parses the `Decorator` production, i.e. `@` followed by a parenthesized expression or
+    /// identifier with optional property accesses and arguments, `@(a)`, `@a.b` or `@a.b(c)`.
+    #[cfg(feature = "decorators")]
+    fn parse_decorator(&mut self) -> Result<Decorator> {
+        let span_start = self.position();
+        self.consume_assert(&punct!("@"))?;
+
+        let expr = if self.current_matches(&punct!("(")) {
+            self.parse_parenthesized_expr()?
+        } else {
+            let expr_start = self.position();
+            let mut expr = self.parse_identifier_reference()?;
+            while self.maybe_consume(&punct!("."))? {
+                let property = self.parse_identifier_name()?;
+                let span = self.span_from(expr_start);
+                expr = fajt_ast::ExprMember {
+                    span,
+                    object: fajt_ast::MemberObject::Expr(Box::new(expr)),
+                    property: fajt_ast::MemberProperty::Ident(property),
+                }
+                .into();
+            }
+
+            if self.current_matches(&punct!("(")) {
+                expr = self.parse_call_expr(expr_start, expr)?;
+            }
+
+            expr
+        };
+
+        let span = self.span_from(span_start);
+        Ok(Decorator { span, expr })
     }
 }
@@ -20,6 +20,17 @@ where
 
         // A parenthesized expression cannot be empty, it must be arrow function.
         if self.peek_matches(&punct!(")")) {
+            let span_start = self.position();
+            self.consume()?;
+            self.consume()?;
+            if !self.current_matches(&punct!("=>")) {
+                return Err(Error::syntax_error(
+                    "Empty parentheses are only valid as arrow function parameters".to_owned(),
+                    self.span_from(span_start),
+                ));
+            }
+
+            self.reader.rewind_to(&start_token)?;
             return Err(Error::arrow_function_not_allowed(
                 self.parse_arrow_function_expr()?,
             ));
@@ -40,19 +51,47 @@ where
                         self.parse_arrow_function_expr()?,
                     ))
                 }
+                // Arrow parameters may end with a trailing comma, `(a, b,) => {}`.
+                &UnexpectedToken(punct!(")"), _) => {
+                    self.reader.rewind_to(&start_token)?;
+                    match self.parse_arrow_function_expr() {
+                        Ok(arrow_function) => {
+                            Err(Error::arrow_function_not_allowed(arrow_function))
+                        }
+                        Err(_) => Err(error),
+                    }
+                }
                 _ => Err(error),
             },
         }
     }
 
-    /// Parses and resolves the `CoverCallExpressionAndAsyncArrowHead` production.
+    /// Parses and resolves the `CoverCallExpressionAndAsyncArrowHead` production, i.e. `async(a)`
+    /// is a call to a function named `async` unless followed by `=>`. Like
+    /// `parse_cover_parenthesized_and_arrow_parameters`, an async arrow function is returned as
+    /// an error that must be caught higher up.
+    ///
+    /// Examples:
+    /// 1. `async(a)` // Function call where `async` is an identifier and not a keyword.
+    /// 2. `async(a) => {}` // Async arrow function with parentheses.
+    /// 3. `async \n (a) => {}` // Error, the call `async(a)` followed by `=>`.
     pub(super) fn parse_cover_call_or_async_arrow_head(&mut self) -> Result<Expr> {
         let start_token = self.current()?.clone();
         match self.parse_covered_call_expression() {
             Ok(expr) if !self.current_matches(&punct!("=>")) => Ok(expr),
             Ok(_) => {
                 self.reader.rewind_to(&start_token)?;
-                self.parse_async_arrow_function_expr()
+                Err(Error::arrow_function_not_allowed(
+                    self.parse_async_arrow_function_expr()?,
+                ))
+            }
+            // `async({ a = 1 }) => {}` is only valid as arrow parameters.
+            Err(error) if matches!(error.kind(), InitializedNameNotAllowed) => {
+                self.reader.rewind_to(&start_token)?;
+                match self.parse_async_arrow_function_expr() {
+                    Ok(arrow_function) => Err(Error::arrow_function_not_allowed(arrow_function)),
+                    Err(_) => Err(error),
+                }
             }
             error => error,
         }
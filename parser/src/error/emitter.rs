@@ -1,3 +1,4 @@
+use crate::error::line_index::LineIndex;
 use crate::error::{expected_token_to_string, ErrorKind};
 use crate::Error;
 use fajt_ast::Span;
@@ -6,6 +7,7 @@ use std::io::Write;
 pub struct ErrorEmitter<'a, 'b, 'c, W> {
     filename: &'a str,
     source: &'b str,
+    line_index: LineIndex<'b>,
     out: &'c mut W,
 }
 
@@ -14,6 +16,7 @@ impl<'a, 'b, 'c, W: Write> ErrorEmitter<'a, 'b, 'c, W> {
         ErrorEmitter {
             filename,
             source,
+            line_index: LineIndex::new(source),
             out,
         }
     }
@@ -21,8 +24,8 @@ impl<'a, 'b, 'c, W: Write> ErrorEmitter<'a, 'b, 'c, W> {
     pub fn emit_error(&mut self, error: &Error) -> std::io::Result<()> {
         let span = &error.span;
         let line_span = self.get_line_boundaries(span);
-        let col_number = span.start - line_span.start + 1;
-        let line_number = self.get_line_number(span);
+        let (_, col_number) = self.line_index.line_col(span.start);
+        let (line_number, _) = self.line_index.line_col(span.end);
 
         writeln!(self.out, "{}", error)?;
 
@@ -48,7 +51,7 @@ impl<'a, 'b, 'c, W: Write> ErrorEmitter<'a, 'b, 'c, W> {
         &mut self,
         error: &Error,
         label: &str,
-        line_number: usize,
+        line_number: u32,
         line_span: Span,
     ) -> std::io::Result<()> {
         let line_number_str = line_number.to_string();
@@ -83,21 +86,14 @@ impl<'a, 'b, 'c, W: Write> ErrorEmitter<'a, 'b, 'c, W> {
         Ok(())
     }
 
-    fn get_line_number(&self, span: &Span) -> usize {
-        self.source[..span.end].matches('\n').count() + 1
-    }
-
+    /// Span of all lines that `span` is on, without the last line terminator.
     fn get_line_boundaries(&self, span: &Span) -> Span {
-        let start = self.source[..span.start]
-            .rfind('\n')
-            .map(|pos| pos + 1)
-            .unwrap_or(0);
-        let end = self.source[span.end..]
-            .find('\n')
-            .map(|pos| pos + span.end)
-            .unwrap_or(self.source.len());
+        let line_span = |offset| {
+            let (line, _) = self.line_index.line_col(offset);
+            self.line_index.line_span(line).unwrap()
+        };
 
-        Span::new(start, end)
+        line_span(span.start).merge(&line_span(span.end))
     }
 
     fn get_kind_description(&self, error: &Error) -> String {
@@ -113,6 +109,10 @@ impl<'a, 'b, 'c, W: Write> ErrorEmitter<'a, 'b, 'c, W> {
                     expected_token_to_string(expected).unwrap_or_default(),
                 )
             }
+            ErrorKind::ExpectedOneOf(_, production, _) => {
+                let token_value = &self.source[error.span.start..error.span.end];
+                format!("Unexpected token, found `{token_value}`, expected {production}")
+            }
             ErrorKind::ExpectedIdentifier(expected) => format!(
                 "Unexpected token, found `{}`, expected identifier",
                 expected_token_to_string(expected).unwrap_or_default(),
@@ -0,0 +1,109 @@
+use fajt_ast::Span;
+
+/// Converts byte offsets, as used by `Span`, to line and column numbers and back. The line starts
+/// are found once when the index is created, so each lookup is a binary search instead of a scan
+/// of the source.
+///
+/// Line and column numbers start at 1. Columns are counted in code points, i.e. `é` is one column
+/// even though it is two bytes.
+///
+/// ```
+/// use fajt_parser::error::line_index::LineIndex;
+///
+/// let index = LineIndex::new("a\né = b");
+/// assert_eq!(index.line_col(5), (2, 3));
+/// assert_eq!(index.offset(2, 3), Some(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineIndex<'a> {
+    source: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    /// Creates an index of `source`. Offsets are relative to the whole source, like the spans
+    /// produced by the lexer, but a leading byte order mark is not counted as a column.
+    pub fn new(source: &'a str) -> Self {
+        let start = source.len() - fajt_lexer::strip_bom(source).len();
+        let line_starts = std::iter::once(start)
+            .chain(line_terminators(&source[start..]).map(|terminator| start + terminator.end))
+            .collect();
+
+        LineIndex {
+            source,
+            line_starts,
+        }
+    }
+
+    /// Number of lines, a source ending with a line terminator ends with an empty line.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Line and column of the byte `offset`. Panics if `offset` is not a char boundary of the
+    /// source, the end of the source is a valid offset.
+    pub fn line_col(&self, offset: usize) -> (u32, u32) {
+        let line = self.line_index(offset);
+        let line_start = self.line_starts[line];
+        let column = self.source[line_start.min(offset)..offset].chars().count();
+        (line as u32 + 1, column as u32 + 1)
+    }
+
+    /// Byte offset of `line` and `column`. Returns `None` if the position is outside of the
+    /// source. The column after the last character of a line is the position of its line
+    /// terminator.
+    pub fn offset(&self, line: u32, column: u32) -> Option<usize> {
+        let line = (line as usize).checked_sub(1)?;
+        let column = (column as usize).checked_sub(1)?;
+
+        let line_start = *self.line_starts.get(line)?;
+        let line_end = self
+            .line_starts
+            .get(line + 1)
+            .copied()
+            .unwrap_or(self.source.len());
+
+        self.source[line_start..line_end]
+            .char_indices()
+            .map(|(pos, _)| line_start + pos)
+            .chain(std::iter::once(line_end))
+            .nth(column)
+    }
+
+    /// Span of `line` without its line terminator, or `None` if there is no such line.
+    pub fn line_span(&self, line: u32) -> Option<Span> {
+        let line = (line as usize).checked_sub(1)?;
+        let start = *self.line_starts.get(line)?;
+        let end = line_terminators(&self.source[start..])
+            .next()
+            .map(|terminator| start + terminator.start)
+            .unwrap_or(self.source.len());
+
+        Some(Span::new(start, end))
+    }
+
+    /// Index of the line containing `offset`, starting at 0.
+    fn line_index(&self, offset: usize) -> usize {
+        self.line_starts
+            .partition_point(|&start| start <= offset)
+            .saturating_sub(1)
+    }
+}
+
+/// Spans of all line terminators in `source`, `\r\n` is a single line terminator.
+fn line_terminators(source: &str) -> impl Iterator<Item = Span> + '_ {
+    let mut chars = source.char_indices().peekable();
+    std::iter::from_fn(move || loop {
+        let (pos, char) = chars.next()?;
+        match char {
+            '\r' if matches!(chars.peek(), Some((_, '\n'))) => {
+                chars.next();
+                return Some(Span::new(pos, pos + 2));
+            }
+            '\n' | '\r' | '\u{2028}' | '\u{2029}' => {
+                return Some(Span::new(pos, pos + char.len_utf8()));
+            }
+            _ => {}
+        }
+    })
+}
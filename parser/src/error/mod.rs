@@ -1,19 +1,23 @@
 use crate::error::ErrorKind::{
-    ArrowFunctionNotAllowed, EndOfStream, ExpectedIdentifier, ForbiddenIdentifier,
-    InitializedNameNotAllowed, SyntaxError, UnexpectedIdent, UnexpectedToken,
+    ArrowFunctionNotAllowed, EndOfStream, ExpectedIdentifier, ExpectedOneOf, ForbiddenIdentifier,
+    InitializedNameNotAllowed, ModuleDeclarationInScript, NestingTooDeep, SyntaxError,
+    UnexpectedIdent, UnexpectedToken,
 };
 use crate::LexerErrorKind;
 use fajt_ast::{Expr, Ident, Span, Spanned};
 use fajt_lexer::error::Error as LexerError;
 use fajt_lexer::token::{Token, TokenValue};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::fmt::Formatter;
 use std::{error, fmt};
 
 pub mod emitter;
+pub mod line_index;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Error {
     kind: ErrorKind,
     span: Span,
@@ -69,7 +73,25 @@ impl Error {
     pub(crate) fn expected_other_token(token: Token, expected: &'static TokenValue) -> Self {
         let span = token.span.clone();
         Error {
-            kind: UnexpectedToken(token.value, Some(expected)),
+            kind: UnexpectedToken(token.value, Some(Cow::Borrowed(expected))),
+            span,
+        }
+    }
+
+    /// `token` can not start `production`, `first_tokens` are the tokens that can, in addition to
+    /// the ones starting an expression when the production is a statement.
+    pub(crate) fn expected_one_of(
+        token: Token,
+        production: &'static str,
+        first_tokens: &'static [TokenValue],
+    ) -> Self {
+        let span = token.span.clone();
+        Error {
+            kind: ExpectedOneOf(
+                token.value,
+                Cow::Borrowed(production),
+                Cow::Borrowed(first_tokens),
+            ),
             span,
         }
     }
@@ -103,14 +125,61 @@ impl Error {
         }
     }
 
+    pub(crate) fn nesting_too_deep(span: Span) -> Self {
+        Error {
+            kind: NestingTooDeep,
+            span,
+        }
+    }
+
+    pub(crate) fn module_declaration_in_script(token: Token) -> Self {
+        let span = token.span.clone();
+        Error {
+            kind: ModuleDeclarationInScript(token.value),
+            span,
+        }
+    }
+
     pub fn kind(&self) -> &ErrorKind {
         &self.kind
     }
 
-    pub(crate) fn span(&self) -> &Span {
+    /// Location of the error in the source.
+    pub fn span(&self) -> &Span {
         &self.span
     }
 
+    /// Description of the error, i.e. the `Display` output without the leading error category.
+    ///
+    /// ```
+    /// let error = fajt_parser::parse_program("a = ;").unwrap_err();
+    /// assert_eq!(error.message(), "Unexpected token `;`");
+    /// assert_eq!(error.to_string(), "Syntax error: Unexpected token `;`");
+    /// ```
+    pub fn message(&self) -> String {
+        match &self.kind {
+            EndOfStream => "Unexpected end of input".to_owned(),
+            ErrorKind::LexerError(e) => e.to_string(),
+            SyntaxError(msg) => msg.clone(),
+            ExpectedIdentifier(token) | UnexpectedToken(token, _) | ExpectedOneOf(token, ..) => {
+                if let Some(token_str) = expected_token_to_string(token) {
+                    format!("Unexpected token `{}`", token_str)
+                } else {
+                    "Unexpected token".to_owned()
+                }
+            }
+            UnexpectedIdent(ident) => format!("Unexpected identifier `{}`", ident.name),
+            ForbiddenIdentifier(identifier) => format!("Forbidden identifier `{}`", identifier),
+            ArrowFunctionNotAllowed(_) => "Arrow function not allowed here".to_owned(),
+            InitializedNameNotAllowed => "Initializer not allowed here".to_owned(),
+            NestingTooDeep => "Maximum nesting depth exceeded".to_owned(),
+            ModuleDeclarationInScript(token) => format!(
+                "Cannot use `{}` statement outside a module",
+                expected_token_to_string(token).unwrap_or_default()
+            ),
+        }
+    }
+
     pub(crate) fn from_kind(kind: ErrorKind, span: Span) -> Error {
         Error { kind, span }
     }
@@ -120,14 +189,19 @@ impl Error {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+/// Serializable together with `Error`, the borrowed parts are owned after deserialization.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ErrorKind {
     EndOfStream,
     LexerError(LexerError),
     SyntaxError(String),
     ExpectedIdentifier(TokenValue),
-    UnexpectedToken(TokenValue, Option<&'static TokenValue>),
+    UnexpectedToken(TokenValue, Option<Cow<'static, TokenValue>>),
+
+    /// The token can not start the named production, i.e. a statement. The tokens are the
+    /// keywords and punctuators that can.
+    ExpectedOneOf(TokenValue, Cow<'static, str>, Cow<'static, [TokenValue]>),
     UnexpectedIdent(Ident),
     ForbiddenIdentifier(String),
 
@@ -138,34 +212,21 @@ pub enum ErrorKind {
     /// Initializer pattern can be parsed in cover productions in
     /// production that does not allow them.
     InitializedNameNotAllowed,
+
+    /// Input is nested deeper than the maximum depth of the parser.
+    NestingTooDeep,
+
+    /// `import` or `export` declaration in a script, the token is the keyword.
+    ModuleDeclarationInScript(TokenValue),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match &self.kind {
-            EndOfStream => write!(f, "Syntax error: Unexpected end of input")?,
-            ErrorKind::LexerError(e) => write!(f, "Lexer error '{}'", e)?,
-            SyntaxError(msg) => write!(f, "Syntax error: {}", msg)?,
-            ExpectedIdentifier(token) | UnexpectedToken(token, _) => {
-                if let Some(token_str) = expected_token_to_string(token) {
-                    write!(f, "Syntax error: Unexpected token `{}`", token_str)?
-                } else {
-                    write!(f, "Syntax error: Unexpected token")?
-                }
-            }
-            UnexpectedIdent(ident) => {
-                write!(f, "Syntax Error: Unexpected identifier `{}`", ident.name)?
-            }
-            ForbiddenIdentifier(identifier) => {
-                write!(f, "Syntax error: Forbidden identifier `{}`", identifier)?
-            }
-            ArrowFunctionNotAllowed(_) => {
-                write!(f, "Syntax error: Arrow function not allowed here")?
-            }
-            InitializedNameNotAllowed => write!(f, "Syntax error: Initializer not allowed here")?,
+            ErrorKind::LexerError(_) => write!(f, "Lexer error '{}'", self.message()),
+            UnexpectedIdent(_) => write!(f, "Syntax Error: {}", self.message()),
+            _ => write!(f, "Syntax error: {}", self.message()),
         }
-
-        Ok(())
     }
 }
 
@@ -10,7 +10,7 @@ use fajt_ast::{update_op, UpdateOperator};
 use fajt_ast::{
     Argument, Callee, Expr, ExprAssignment, ExprAwait, ExprCall, ExprConditional, ExprLiteral,
     ExprMetaProperty, ExprNew, ExprSequence, ExprThis, ExprUnary, ExprUpdate, ExprYield, Ident,
-    Literal, MemberObject, Span, Super,
+    Literal, MemberObject, Span, Spread, Super,
 };
 use fajt_common::io::{PeekRead, ReReadWithState};
 use fajt_lexer::punct;
@@ -59,7 +59,13 @@ where
             token_matches!(ok: keyword!("yield")) if self.context.is_yield => {
                 self.parse_yield_expr()
             }
-            token_matches!(ok: keyword!("async")) => self.parse_assignment_expr_async(),
+            // `async(a) => {}` is parsed as a cover call expression, since `async(a)` may also be a
+            // function call, see `parse_cover_call_or_async_arrow_head`.
+            token_matches!(ok: keyword!("async"))
+                if self.peek_is_identifier() && !self.followed_by_new_line() =>
+            {
+                self.parse_async_arrow_function_expr()
+            }
             _ if self.is_identifier() && self.peek_matches(&punct!("=>")) => {
                 self.parse_arrow_function_expr()
             }
@@ -181,21 +187,6 @@ where
         }
     }
 
-    /// Parses the part of `AssignmentExpression` that starts with the `async` keyword.
-    /// Examples:
-    /// 1. `async => {}` // Non async arrow function with the name async.
-    /// 2. `async a => {}` // Async arrow function without parameter parentheses.
-    /// 3. `async(a) => {}` // Async arrow function with parentheses.
-    /// 4. `async(a)` // Function call where `async` is an identifier and not a keyword.
-    fn parse_assignment_expr_async(&mut self) -> Result<Expr> {
-        match self.peek() {
-            token_matches!(opt: punct!("=>")) => self.parse_arrow_function_expr(),
-            token_matches!(opt: punct!("(")) => self.parse_cover_call_or_async_arrow_head(),
-            _ if self.peek_is_identifier() => self.parse_async_arrow_function_expr(),
-            _ => self.parse_conditional_expr(),
-        }
-    }
-
     /// Parses the part of `AssignmentExpression` that is an assignment.
     fn parse_assignment(
         &mut self,
@@ -203,7 +194,7 @@ where
         left: PatternOrExpr,
         operator: AssignmentOperator,
     ) -> Result<Expr> {
-        let right = self.parse_assignment_expr()?;
+        let right = self.nested(Self::parse_assignment_expr)?;
         let span = self.span_from(span_start);
 
         Ok(ExprAssignment {
@@ -230,6 +221,9 @@ where
             token_matches!(ok: punct!("^=")) => Some(assignment_op!("^=")),
             token_matches!(ok: punct!("|=")) => Some(assignment_op!("|=")),
             token_matches!(ok: punct!("**=")) => Some(assignment_op!("**=")),
+            token_matches!(ok: punct!("&&=")) => Some(assignment_op!("&&=")),
+            token_matches!(ok: punct!("||=")) => Some(assignment_op!("||=")),
+            token_matches!(ok: punct!("??=")) => Some(assignment_op!("??=")),
             _ => None,
         };
 
@@ -245,7 +239,11 @@ where
         let span_start = self.position();
 
         self.consume_assert(&punct!("("))?;
-        let expr = self.with_context(self.context.with_in(true)).parse_expr()?;
+        let expr = self.nested(|parser| {
+            parser
+                .with_context(parser.context.with_in(true))
+                .parse_expr()
+        })?;
         self.consume_assert(&punct!(")"))?;
 
         let span = self.span_from(span_start);
@@ -259,7 +257,13 @@ where
     /// Parses the `YieldExpression` production.
     fn parse_yield_expr(&mut self) -> Result<Expr> {
         let span_start = self.position();
-        self.consume_assert(&keyword!("yield"))?;
+        let yield_token = self.consume_assert(&keyword!("yield"))?;
+        if self.context.in_parameters {
+            return Err(Error::syntax_error(
+                "Yield expression not allowed in formal parameters".to_owned(),
+                yield_token.span,
+            ));
+        }
 
         if self.is_end() || self.expr_ended() {
             let span = self.span_from(span_start);
@@ -272,7 +276,7 @@ where
         }
 
         let delegate = self.maybe_consume(&punct!("*"))?;
-        let argument = self.parse_assignment_expr()?;
+        let argument = self.nested(Self::parse_assignment_expr)?;
         let span = self.span_from(span_start);
         Ok(ExprYield {
             span,
@@ -289,11 +293,15 @@ where
 
         if self.current_matches(&punct!("?")) {
             self.consume()?;
-            let consequent = self.parse_assignment_expr()?;
+            let consequent = self.nested(|parser| {
+                parser
+                    .with_context(parser.context.with_in(true))
+                    .parse_assignment_expr()
+            })?;
 
             self.consume_assert(&punct!(":"))?;
 
-            let alternate = self.parse_assignment_expr()?;
+            let alternate = self.nested(Self::parse_assignment_expr)?;
             let span = self.span_from(span_start);
             Ok(ExprConditional {
                 span,
@@ -308,29 +316,54 @@ where
     }
 
     /// Parses the `UnaryExpression` production.
+    ///
+    /// Prefix operators are consumed in a loop and applied innermost first, so long chains of
+    /// operators do not recurse.
     pub(super) fn parse_unary_expr(&mut self) -> Result<Expr> {
-        let span_start = self.position();
+        enum Prefix {
+            Unary(UnaryOperator),
+            Update(UpdateOperator),
+            Await,
+        }
 
-        let unary_operator = self.parse_optional_unary_operator();
-        if let Some(operator) = unary_operator {
-            return self.parse_unary_expr_with_operator(span_start, operator);
+        let mut prefixes = Vec::new();
+        loop {
+            self.check_depth(prefixes.len())?;
+
+            let span_start = self.position();
+            let prefix = if let Some(operator) = self.parse_optional_unary_operator() {
+                Prefix::Unary(operator)
+            } else if let Some(operator) = self.parse_optional_update_operator() {
+                Prefix::Update(operator)
+            } else if self.context.is_await && token_matches!(self.current()?, keyword!("await")) {
+                self.consume_await()?;
+                Prefix::Await
+            } else {
+                break;
+            };
+
+            prefixes.push((span_start, prefix));
         }
 
-        if self.context.is_await && token_matches!(self.current()?, keyword!("await")) {
-            return self.parse_await_expr();
+        let mut expr = self.parse_update_expr()?;
+        for (span_start, prefix) in prefixes.into_iter().rev() {
+            expr = match prefix {
+                Prefix::Unary(operator) => self.unary_expr(span_start, operator, expr)?,
+                Prefix::Update(operator) => self.prefix_update_expr(span_start, operator, expr)?,
+                Prefix::Await => self.await_expr(span_start, expr),
+            };
         }
 
-        self.parse_update_expr()
+        Ok(expr)
     }
 
-    /// Parses a unary expression when operator is already consumed.
-    fn parse_unary_expr_with_operator(
-        &mut self,
+    /// Returns the `UnaryExpression` of `operator` applied to the already parsed `argument`.
+    fn unary_expr(
+        &self,
         span_start: usize,
         operator: UnaryOperator,
+        argument: Expr,
     ) -> Result<Expr> {
-        let argument = self.parse_unary_expr()?;
-
         if operator == unary_op!("delete") {
             argument.early_errors_unary_delete(&self.context)?;
         }
@@ -363,26 +396,32 @@ where
         operator
     }
 
-    /// Parses the `AwaitExpression` production.
-    fn parse_await_expr(&mut self) -> Result<Expr> {
-        let span_start = self.position();
-        self.consume_assert(&keyword!("await"))?;
+    /// Consumes the `await` of the `AwaitExpression` production.
+    fn consume_await(&mut self) -> Result<()> {
+        let await_token = self.consume_assert(&keyword!("await"))?;
+        if self.context.in_parameters {
+            return Err(Error::syntax_error(
+                "Await expression not allowed in formal parameters".to_owned(),
+                await_token.span,
+            ));
+        }
 
-        let argument = self.parse_unary_expr()?.into();
+        Ok(())
+    }
 
+    /// Returns the `AwaitExpression` of the already parsed `argument`.
+    fn await_expr(&self, span_start: usize, argument: Expr) -> Expr {
         let span = self.span_from(span_start);
-        Ok(ExprAwait { span, argument }.into())
+        ExprAwait {
+            span,
+            argument: argument.into(),
+        }
+        .into()
     }
 
-    /// Parses the `UpdateExpression` production.
+    /// Parses the `UpdateExpression` production, except for prefix operators which are parsed by
+    /// `parse_unary_expr`.
     fn parse_update_expr(&mut self) -> Result<Expr> {
-        let span_start = self.position();
-
-        let prefix_operator = self.parse_optional_update_operator();
-        if let Some(operator) = prefix_operator {
-            return self.parse_prefix_update_expr(span_start, operator);
-        }
-
         let expr = self.parse_left_hand_side_expr()?;
 
         // New line is not allowed between argument and postfix update operator.
@@ -399,13 +438,14 @@ where
         }
     }
 
-    /// Parses the `++/-- UnaryExpression` of the `UpdateExpression` production.
-    fn parse_prefix_update_expr(
-        &mut self,
+    /// Returns the `++/-- UnaryExpression` of the `UpdateExpression` production, of the already
+    /// parsed `argument`.
+    fn prefix_update_expr(
+        &self,
         span_start: usize,
         operator: UpdateOperator,
+        argument: Expr,
     ) -> Result<Expr> {
-        let argument = self.parse_unary_expr()?;
         argument.early_errors_update_expr_argument(&self.context)?;
 
         let span = self.span_from(span_start);
@@ -462,6 +502,12 @@ where
             token_matches!(ok: keyword!("import")) if self.peek_matches(&punct!("(")) => {
                 self.parse_import_call_expr()
             }
+            token_matches!(ok: keyword!("async"))
+                if self.peek_matches(&punct!("(")) && !self.followed_by_new_line() =>
+            {
+                let expr = self.parse_cover_call_or_async_arrow_head()?;
+                self.parse_recursive_call_expression(span_start, expr)
+            }
             _ => {
                 let span_start = self.position();
                 let expr = self.parse_new_or_member_expr()?;
@@ -471,9 +517,9 @@ where
         }?;
 
         if self.current_matches(&punct!("?.")) {
-            // The NewExpression production handles nested news and is not included in the
+            // A new without arguments is a NewExpression, which is not included in the
             // OptionalExpression production as a base for the chain.
-            if expr.is_nested_new() {
+            if expr.is_new_without_arguments() {
                 return Err(Error::unexpected_token(self.consume()?));
             }
 
@@ -530,7 +576,7 @@ where
 
         if !self.context.super_call_allowed {
             return Err(Error::syntax_error(
-                "super() now allowed here".to_owned(),
+                "super() not allowed here".to_owned(),
                 span,
             ));
         }
@@ -549,7 +595,7 @@ where
         let span_start = self.position();
         self.consume()?;
 
-        let (arguments_span, arguments) = self.parse_import_argument()?;
+        let (arguments_span, arguments) = self.parse_import_arguments()?;
 
         let span = self.span_from(span_start);
         Ok(ExprCall {
@@ -561,15 +607,35 @@ where
         .into())
     }
 
-    fn parse_import_argument(&mut self) -> Result<(Span, Vec<Argument>)> {
+    /// Parses the arguments of an `ImportCall`, i.e. the specifier and optionally an options
+    /// argument, `import(a, { with: { type: "json" } })`.
+    fn parse_import_arguments(&mut self) -> Result<(Span, Vec<Argument>)> {
         let span_start = self.position();
-
         self.consume_assert(&punct!("("))?;
-        let expr = self.parse_assignment_expr()?;
+
+        let mut arguments = Vec::new();
+        loop {
+            let expr = self
+                .with_context(self.context.with_in(true))
+                .parse_assignment_expr()?;
+            arguments.push(Argument::Expr(expr));
+
+            if !self.maybe_consume(&punct!(","))? || self.current_matches(&punct!(")")) {
+                break;
+            }
+
+            if arguments.len() == 2 {
+                return Err(Error::syntax_error(
+                    "Import calls accept at most two arguments".to_owned(),
+                    self.current()?.span.clone(),
+                ));
+            }
+        }
+
         self.consume_assert(&punct!(")"))?;
 
         let span = self.span_from(span_start);
-        Ok((span, vec![Argument::Expr(expr)]))
+        Ok((span, arguments))
     }
 
     /// Parses the `MemberExpression` and `NewExpression` productions.
@@ -626,7 +692,7 @@ where
         let span_start = self.position();
         self.consume()?;
 
-        let callee = self.parse_new_or_member_expr()?;
+        let callee = self.nested(Self::parse_new_or_member_expr)?;
         let (arguments_span, arguments) = self.parse_optional_arguments()?;
 
         let span = self.span_from(span_start);
@@ -710,6 +776,10 @@ where
 
     /// Parses the `Arguments` production.
     pub(super) fn parse_arguments(&mut self) -> Result<(Span, Vec<Argument>)> {
+        self.nested(Self::parse_nested_arguments)
+    }
+
+    fn parse_nested_arguments(&mut self) -> Result<(Span, Vec<Argument>)> {
         let span_start = self.position();
         self.consume_assert(&punct!("("))?;
 
@@ -731,13 +801,22 @@ where
     }
 
     fn parse_argument(&mut self) -> Result<Argument> {
-        Ok(if self.maybe_consume(&punct!("..."))? {
-            Argument::Spread(self.parse_assignment_expr()?)
+        Ok(if self.current_matches(&punct!("...")) {
+            Argument::Spread(self.parse_spread()?)
         } else {
             Argument::Expr(self.parse_assignment_expr()?)
         })
     }
 
+    /// Parses `... AssignmentExpression`, used by arguments, array literals and object literals.
+    pub(super) fn parse_spread(&mut self) -> Result<Spread> {
+        let span_start = self.position();
+        self.consume_assert(&punct!("..."))?;
+        let argument = self.parse_assignment_expr()?;
+        let span = self.span_from(span_start);
+        Ok(Spread { span, argument })
+    }
+
     /// Parses arguments where optional.
     pub(super) fn parse_optional_arguments(&mut self) -> Result<(Option<Span>, Vec<Argument>)> {
         if self.current_matches(&punct!("(")) {
@@ -752,6 +831,8 @@ where
     fn parse_primary_expr(&mut self) -> Result<Expr> {
         Ok(match self.current()? {
             token_matches!(keyword!("this")) => self.parse_this_expr()?,
+            #[cfg(feature = "do-expressions")]
+            token_matches!(keyword!("do")) => self.parse_do_expr()?,
             token_matches!(keyword!("null")) => self.consume_literal(Literal::Null)?,
             token_matches!(keyword!("true")) => self.consume_literal(Literal::Boolean(true))?,
             token_matches!(keyword!("false")) => self.consume_literal(Literal::Boolean(false))?,
@@ -762,7 +843,15 @@ where
             token_matches!(keyword!("class")) => self
                 .with_context(self.context.with_strict(true))
                 .parse_class_expr()?,
-            token_matches!(keyword!("async")) if !self.followed_by_new_line() => {
+            #[cfg(feature = "decorators")]
+            token_matches!(punct!("@")) => self
+                .with_context(self.context.with_strict(true))
+                .parse_class_expr()?,
+            // Otherwise `async` is an identifier reference, async arrow functions are parsed as
+            // part of `AssignmentExpression`.
+            token_matches!(keyword!("async"))
+                if self.peek_matches(&keyword!("function")) && !self.followed_by_new_line() =>
+            {
                 self.parse_async_function_expr()?
             }
             token_matches!(punct!("/")) => {
@@ -790,13 +879,15 @@ where
     /// Parses the `RegularExpressionLiteral` production.
     pub(super) fn parse_regexp_literal(&mut self) -> Result<Expr> {
         let regexp = self.parse_literal()?;
-        debug_assert!(matches!(
-            regexp,
-            Expr::Literal(ExprLiteral {
-                literal: Literal::Regexp(_),
-                ..
-            })
-        ));
+        if let Expr::Literal(ExprLiteral {
+            span,
+            literal: Literal::Regexp(regexp),
+        }) = &regexp
+        {
+            validate_regexp_flags(&regexp.flags, span)?;
+        } else {
+            debug_assert!(false, "Expected regexp literal, got {regexp:?}");
+        }
 
         Ok(regexp)
     }
@@ -807,8 +898,20 @@ where
         Ok(ExprThis::new(token.span).into())
     }
 
+    /// Parses a `do` expression from the do expressions proposal, i.e. `do { 1 }`. The value of the
+    /// expression is the completion value of the block.
+    #[cfg(feature = "do-expressions")]
+    fn parse_do_expr(&mut self) -> Result<Expr> {
+        let span_start = self.position();
+        self.consume_assert(&keyword!("do"))?;
+
+        let body = self.parse_block()?;
+        let span = self.span_from(span_start);
+        Ok(fajt_ast::ExprDo { span, body }.into())
+    }
+
     /// Parses the `IdentifierReference` production.
-    fn parse_identifier_reference(&mut self) -> Result<Expr> {
+    pub(super) fn parse_identifier_reference(&mut self) -> Result<Expr> {
         let ident = self.parse_identifier()?;
         Ok(ident.into())
     }
@@ -825,3 +928,24 @@ where
             || self.current_matches(&punct!("]"))
     }
 }
+
+/// Flags are valid if each one of them is a known flag and none of them are repeated.
+fn validate_regexp_flags(flags: &str, span: &Span) -> Result<()> {
+    for (i, flag) in flags.char_indices() {
+        if !"dgimsuy".contains(flag) {
+            return Err(Error::syntax_error(
+                format!("Invalid regular expression flag `{flag}`"),
+                span.clone(),
+            ));
+        }
+
+        if flags[..i].contains(flag) {
+            return Err(Error::syntax_error(
+                format!("Duplicate regular expression flag `{flag}`"),
+                span.clone(),
+            ));
+        }
+    }
+
+    Ok(())
+}
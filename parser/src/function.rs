@@ -1,5 +1,6 @@
 use crate::error::Result;
-use crate::{DirectivePrologueSemantics, Error, Parser, ThenTry};
+use crate::static_semantics::FormalParametersSemantics;
+use crate::{DirectivePrologueSemantics, Error, Parser, StmtListSemantics, ThenTry};
 use fajt_ast::{
     ArrowFunctionBody, BindingElement, Body, DeclFunction, Expr, ExprArrowFunction, ExprFunction,
     FormalParameters, Ident, Stmt,
@@ -35,7 +36,13 @@ where
         span_start: usize,
         asynchronous: bool,
     ) -> Result<Expr> {
-        let (binding_parameter, parameters) = self.parse_arrow_function_parameters()?;
+        // `await` is reserved in the parameters of async arrow functions.
+        let (binding_parameter, parameters) = self
+            .with_context(
+                self.context
+                    .with_await(self.context.is_await || asynchronous),
+            )
+            .parse_arrow_function_parameters()?;
 
         let arrow = self.consume_assert(&punct!("=>"))?;
         if arrow.first_on_line {
@@ -51,6 +58,12 @@ where
             )
             .parse_concise_body()?;
 
+        // Arrow function parameters are `UniqueFormalParameters`, duplicates are never allowed.
+        if let ArrowFunctionBody::Body(body) = &body {
+            parameters.early_errors_forbidden_use_strict(&body.directives)?;
+        }
+        parameters.early_errors_unique()?;
+
         let span = self.span_from(span_start);
         Ok(ExprArrowFunction {
             span,
@@ -65,10 +78,10 @@ where
     /// Parses the `ArrowParameters` production. First item in the tuple is `true` if parameters are
     /// a `BindingIdentifier`.
     fn parse_arrow_function_parameters(&mut self) -> Result<(bool, FormalParameters)> {
-        let (binding_parameter, parameters) = if self.is_identifier() {
-            (true, self.parse_arrow_identifier_argument()?)
-        } else {
+        let (binding_parameter, parameters) = if self.current_matches(&punct!("(")) {
             (false, self.parse_formal_parameters()?)
+        } else {
+            (true, self.parse_arrow_identifier_argument()?)
         };
 
         Ok((binding_parameter, parameters))
@@ -80,7 +93,7 @@ where
             Ok(ArrowFunctionBody::Body(self.parse_function_body()?))
         } else {
             Ok(ArrowFunctionBody::Expr(
-                self.parse_assignment_expr()?.into(),
+                self.nested(Self::parse_assignment_expr)?.into(),
             ))
         }
     }
@@ -88,7 +101,7 @@ where
     /// Parses the `ArrowParameters` production.
     pub(super) fn parse_arrow_identifier_argument(&mut self) -> Result<FormalParameters> {
         let span_start = self.position();
-        let identifier = self.parse_identifier()?;
+        let identifier = self.parse_binding_identifier()?;
         let span = self.span_from(span_start);
         Ok(FormalParameters {
             span: span.clone(),
@@ -140,7 +153,9 @@ where
     /// Parses the parts from the optional identifier and forward for async/non-async
     /// function/generator expressions, assumes context is set correctly.
     fn parse_function_expr_content(&mut self, span_start: usize) -> Result<Expr> {
-        let identifier = self.is_identifier().then_try(|| self.parse_identifier())?;
+        let identifier = self
+            .is_identifier()
+            .then_try(|| self.parse_binding_identifier())?;
         let parameters = self.parse_formal_parameters()?;
         let body = self.parse_function_body()?;
 
@@ -225,7 +240,7 @@ where
             let current = self.current().unwrap();
             Ok(Ident::dummy(current.span.start))
         } else {
-            self.parse_identifier()
+            self.parse_binding_identifier()
         }
     }
 
@@ -235,22 +250,30 @@ where
 
         self.consume_assert(&punct!("("))?;
 
+        let mut parser = self.with_context(self.context.with_in_parameters(true));
         let mut parameters = Vec::new();
         let mut rest = None;
         loop {
-            match self.current()? {
+            match parser.current()? {
                 token_matches!(punct!(")")) => {
-                    self.consume()?;
+                    parser.consume()?;
                     break;
                 }
                 token_matches!(punct!("...")) => {
-                    rest = Some(self.parse_binding_rest_element()?);
-                    self.consume_assert(&punct!(")"))?;
+                    rest = Some(parser.parse_binding_rest_element()?);
+                    if parser.current_matches(&punct!(",")) {
+                        return Err(Error::syntax_error(
+                            "Rest parameter must be last formal parameter".to_owned(),
+                            parser.current()?.span.clone(),
+                        ));
+                    }
+
+                    parser.consume_assert(&punct!(")"))?;
                     break;
                 }
                 _ => {
-                    parameters.push(self.parse_binding_element()?);
-                    self.consume_list_delimiter(&punct!(")"))?;
+                    parameters.push(parser.parse_binding_element()?);
+                    parser.consume_list_delimiter(&punct!(")"))?;
                 }
             }
         }
@@ -265,6 +288,10 @@ where
 
     /// Parses the `FunctionBody` or `AsyncFunctionBody` production.
     pub(super) fn parse_function_body(&mut self) -> Result<Body> {
+        self.nested(Self::parse_nested_function_body)
+    }
+
+    fn parse_nested_function_body(&mut self) -> Result<Body> {
         let span_start = self.position();
         self.consume_assert(&punct!("{"))?;
 
@@ -272,8 +299,16 @@ where
         let is_strict = self.context.is_strict || directives.as_slice().contains_strict();
 
         let statements = self
-            .with_context(self.context.with_strict(is_strict).with_return(true))
+            .with_context(
+                self.context
+                    .with_strict(is_strict)
+                    .with_return(true)
+                    .with_in_parameters(false)
+                    .with_in_iteration(false)
+                    .with_in_switch(false),
+            )
             .parse_function_body_stmt_list()?;
+        statements.early_errors_undefined_targets()?;
 
         let span = self.span_from(span_start);
         Ok(Body {
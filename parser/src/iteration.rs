@@ -1,5 +1,5 @@
 use crate::error::Result;
-use crate::static_semantics::ExprSemantics;
+use crate::static_semantics::{BindingPatternSemantics, ExprSemantics};
 use crate::{Error, Parser, ThenTry};
 use fajt_ast::{
     ForBinding, ForDeclaration, ForInit, Stmt, StmtDoWhile, StmtFor, StmtForIn, StmtForOf,
@@ -21,7 +21,7 @@ where
         let span_start = self.position();
         self.consume_assert(&keyword!("do"))?;
 
-        let body = self.parse_stmt()?;
+        let body = self.parse_iteration_body()?;
 
         self.consume_assert(&keyword!("while"))?;
         self.consume_assert(&punct!("("))?;
@@ -50,7 +50,7 @@ where
 
         self.consume_assert(&punct!(")"))?;
 
-        let body = self.parse_stmt()?;
+        let body = self.parse_iteration_body()?;
 
         let span = self.span_from(span_start);
         Ok(StmtWhile {
@@ -66,6 +66,14 @@ where
         let span_start = self.position();
         self.consume_assert(&keyword!("for"))?;
 
+        if !self.context.is_await && self.current_matches(&keyword!("await")) {
+            return Err(Error::syntax_error(
+                "'for await' is only valid in async functions and the top level of modules"
+                    .to_owned(),
+                self.current()?.span.clone(),
+            ));
+        }
+
         let asynchronous = self.context.is_await && self.maybe_consume(&keyword!("await"))?;
         self.consume_assert(&punct!("("))?;
 
@@ -108,7 +116,7 @@ where
             .then_try(|| self.with_context(self.context.with_in(true)).parse_expr())?;
         self.consume_assert(&punct!(")"))?;
 
-        let body = self.parse_stmt()?;
+        let body = self.parse_iteration_body()?;
         let span = self.span_from(span_start);
 
         Ok(Some(
@@ -154,7 +162,7 @@ where
 
         self.consume_assert(&punct!(")"))?;
 
-        let body = self.parse_stmt()?;
+        let body = self.parse_iteration_body()?;
         let span = self.span_from(span_start);
         Ok(StmtForIn {
             span,
@@ -173,11 +181,13 @@ where
     ) -> Result<Stmt> {
         self.consume_assert(&keyword!("of"))?;
 
-        let right = self.with_context(self.context.with_in(true)).parse_expr()?;
+        let right = self
+            .with_context(self.context.with_in(true))
+            .parse_assignment_expr()?;
 
         self.consume_assert(&punct!(")"))?;
 
-        let body = self.parse_stmt()?;
+        let body = self.parse_iteration_body()?;
         let span = self.span_from(span_start);
         Ok(StmtForOf {
             span,
@@ -196,6 +206,10 @@ where
 
         if let Some(kind) = variable_kind {
             let binding = self.parse_binding_pattern()?;
+            if kind != VariableKind::Var {
+                binding.early_errors_lexical_binding()?;
+            }
+
             return Ok(ForDeclaration::Declaration(ForBinding {
                 span: self.span_from(span_start),
                 kind,
@@ -248,7 +262,7 @@ where
     ) -> Result<ForInit> {
         let declarations = self
             .with_context(self.context.with_in(false))
-            .parse_variable_declarations()?;
+            .parse_variable_declarations(&kind)?;
 
         let span = self.span_from(span_start);
         Ok(ForInit::Declaration(StmtVariable {
@@ -274,4 +288,11 @@ where
 
         Ok(variable_kind)
     }
+
+    /// Parses the body statement of an iteration statement, where `break` and `continue` are
+    /// allowed.
+    fn parse_iteration_body(&mut self) -> Result<Stmt> {
+        self.with_context(self.context.with_in_iteration(true))
+            .parse_stmt()
+    }
 }
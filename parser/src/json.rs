@@ -0,0 +1,311 @@
+use crate::error::Result;
+use crate::{Error, Parser};
+use fajt_ast::{
+    unary_op, ArrayElement, Expr, ExprLiteral, ExprUnary, LitArray, LitObject, Literal,
+    NamedProperty, PropertyDefinition, PropertyName, Span, Spanned,
+};
+use fajt_common::io::{PeekRead, ReReadWithState};
+use fajt_lexer::punct;
+use fajt_lexer::token::{Token, TokenValue};
+use fajt_lexer::token_matches;
+use fajt_lexer::{keyword, LexerState};
+
+impl<I> Parser<'_, I>
+where
+    I: PeekRead<Token, Error = fajt_lexer::error::Error>,
+    I: ReReadWithState<Token, State = LexerState, Error = fajt_lexer::error::Error>,
+{
+    /// Parses the `JSONText` production, `source` must be the source the parser is reading from.
+    ///
+    /// The result is the same `Expr` as parsing the JSON text as a JavaScript expression, i.e.
+    /// negative numbers are unary `-` expressions.
+    pub(super) fn parse_json_text(&mut self, source: &str) -> Result<Expr> {
+        let value = self.parse_json_value(source)?;
+
+        if !self.is_end() {
+            return Err(Error::unexpected_token(self.current()?.clone()));
+        }
+
+        validate_json_whitespace(source, self.reader.position(), source.len())?;
+        Ok(value)
+    }
+
+    /// Parses the `JSONValue` production.
+    fn parse_json_value(&mut self, source: &str) -> Result<Expr> {
+        match self.current()? {
+            token_matches!(keyword!("null")) => {
+                let token = self.consume_json(source)?;
+                Ok(json_literal(token.span, Literal::Null))
+            }
+            token_matches!(keyword!("true")) => {
+                let token = self.consume_json(source)?;
+                Ok(json_literal(token.span, Literal::Boolean(true)))
+            }
+            token_matches!(keyword!("false")) => {
+                let token = self.consume_json(source)?;
+                Ok(json_literal(token.span, Literal::Boolean(false)))
+            }
+            token_matches!(punct!("{")) => self.nested(|parser| parser.parse_json_object(source)),
+            token_matches!(punct!("[")) => self.nested(|parser| parser.parse_json_array(source)),
+            token_matches!(punct!("-")) => self.parse_json_negative_number(source),
+            token_matches!(@literal) => self.parse_json_literal(source),
+            _ => Err(Error::unexpected_token(self.consume()?)),
+        }
+    }
+
+    /// Parses a `JSONNumber` with a leading `-`, no whitespace is allowed after the `-`.
+    fn parse_json_negative_number(&mut self, source: &str) -> Result<Expr> {
+        let minus = self.consume_json(source)?;
+        if !token_matches!(self.current()?, @literal) || self.position() != minus.span.end {
+            return Err(Error::unexpected_token(self.consume()?));
+        }
+
+        let argument = self.parse_json_literal(source)?;
+        if !matches!(
+            &argument,
+            Expr::Literal(ExprLiteral {
+                literal: Literal::Number(_),
+                ..
+            })
+        ) {
+            return Err(Error::syntax_error(
+                "Expected number after `-` in JSON".to_owned(),
+                argument.span().clone(),
+            ));
+        }
+
+        let span = self.span_from(minus.span.start);
+        Ok(ExprUnary {
+            span,
+            operator: unary_op!("-"),
+            argument: Box::new(argument),
+        }
+        .into())
+    }
+
+    /// Parses a `JSONString` or `JSONNumber`.
+    fn parse_json_literal(&mut self, source: &str) -> Result<Expr> {
+        let token = self.consume_json(source)?;
+        let raw = &source[token.span.start..token.span.end];
+        match &token.value {
+            TokenValue::Literal(Literal::String(_)) => validate_json_string(raw, &token.span)?,
+            TokenValue::Literal(Literal::Number(_)) => validate_json_number(raw, &token.span)?,
+            _ => return Err(Error::unexpected_token(token)),
+        }
+
+        if let TokenValue::Literal(literal) = token.value {
+            Ok(json_literal(token.span, literal))
+        } else {
+            unreachable!()
+        }
+    }
+
+    /// Parses the `JSONArray` production, trailing commas and elisions are not allowed.
+    fn parse_json_array(&mut self, source: &str) -> Result<Expr> {
+        let span_start = self.position();
+        self.consume_json(source)?;
+
+        let mut elements = Vec::new();
+        if !self.current_matches(&punct!("]")) {
+            loop {
+                elements.push(ArrayElement::Expr(self.parse_json_value(source)?));
+                if !self.consume_json_list_delimiter(source, &punct!("]"))? {
+                    break;
+                }
+            }
+        }
+
+        self.consume_json(source)?;
+
+        let span = self.span_from(span_start);
+        Ok(json_literal(span, Literal::Array(LitArray { elements })))
+    }
+
+    /// Parses the `JSONObject` production, property names must be strings and trailing commas
+    /// are not allowed.
+    fn parse_json_object(&mut self, source: &str) -> Result<Expr> {
+        let span_start = self.position();
+        self.consume_json(source)?;
+
+        let mut props = Vec::new();
+        if !self.current_matches(&punct!("}")) {
+            loop {
+                props.push(self.parse_json_member(source)?);
+                if !self.consume_json_list_delimiter(source, &punct!("}"))? {
+                    break;
+                }
+            }
+        }
+
+        self.consume_json(source)?;
+
+        let span = self.span_from(span_start);
+        Ok(json_literal(span, Literal::Object(LitObject { props })))
+    }
+
+    /// Parses the `JSONMember` production, i.e. `"name": value`.
+    fn parse_json_member(&mut self, source: &str) -> Result<PropertyDefinition> {
+        let span_start = self.position();
+        if !self.current_matches_string_literal() {
+            return Err(Error::syntax_error(
+                "Property names must be double quoted strings in JSON".to_owned(),
+                self.current()?.span.clone(),
+            ));
+        }
+
+        let name = match self.parse_json_literal(source)? {
+            Expr::Literal(ExprLiteral {
+                literal: Literal::String(string),
+                ..
+            }) => PropertyName::String(string),
+            _ => unreachable!(),
+        };
+
+        let colon = self.consume_json(source)?;
+        if colon.value != punct!(":") {
+            return Err(Error::expected_other_token(colon, &punct!(":")));
+        }
+
+        let value = self.parse_json_value(source)?;
+        let span = self.span_from(span_start);
+        Ok(PropertyDefinition::Named(NamedProperty {
+            span,
+            name,
+            value,
+        }))
+    }
+
+    /// Consumes a `,` followed by another element, or ensures the list ends with `list_end`.
+    /// Returns `true` if there is another element.
+    fn consume_json_list_delimiter(&mut self, source: &str, list_end: &TokenValue) -> Result<bool> {
+        if self.current_matches(list_end) {
+            return Ok(false);
+        }
+
+        let token = self.consume_json(source)?;
+        if token.value != punct!(",") {
+            return Err(Error::expected_other_token(token, &punct!(",")));
+        }
+
+        if self.current_matches(list_end) {
+            return Err(Error::syntax_error(
+                "Trailing commas are not allowed in JSON".to_owned(),
+                token.span,
+            ));
+        }
+
+        Ok(true)
+    }
+
+    /// Consumes current token after validating that only JSON whitespace precedes it.
+    fn consume_json(&mut self, source: &str) -> Result<Token> {
+        if let Ok(token) = self.current() {
+            validate_json_whitespace(source, self.reader.position(), token.span.start)?;
+        }
+
+        self.consume()
+    }
+}
+
+fn json_literal(span: Span, literal: Literal) -> Expr {
+    ExprLiteral { span, literal }.into()
+}
+
+/// Returns error if `source[start..end]` is anything but JSON whitespace, i.e. comments or
+/// whitespace only allowed in JavaScript. A byte order mark at the start of the source is allowed.
+fn validate_json_whitespace(source: &str, start: usize, end: usize) -> Result<()> {
+    let start = start.max(source.len() - fajt_lexer::strip_bom(source).len());
+    let gap = &source[start..end];
+    if let Some(offset) = gap.find(|c| !matches!(c, ' ' | '\t' | '\n' | '\r')) {
+        let position = start + offset;
+        let message = if gap[offset..].starts_with('/') || gap[offset..].starts_with("<!--") {
+            "Comments are not allowed in JSON"
+        } else {
+            "Invalid whitespace in JSON"
+        };
+
+        return Err(Error::syntax_error(
+            message.to_owned(),
+            Span::new(position, position + 1),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns error if `raw` is not a valid `JSONString`, i.e. single quoted, contains control
+/// characters or escapes that are only valid in JavaScript.
+fn validate_json_string(raw: &str, span: &Span) -> Result<()> {
+    let error = || {
+        Err(Error::syntax_error(
+            "Invalid string in JSON".to_owned(),
+            span.clone(),
+        ))
+    };
+
+    if !raw.starts_with('"') {
+        return error();
+    }
+
+    let mut chars = raw[1..raw.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{0}'..='\u{1f}' => return error(),
+            '\\' => match chars.next() {
+                Some('"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't') => {}
+                Some('u') => {
+                    let is_hex4 =
+                        (0..4).all(|_| chars.next().is_some_and(|c| c.is_ascii_hexdigit()));
+                    if !is_hex4 {
+                        return error();
+                    }
+                }
+                _ => return error(),
+            },
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns error if `raw` is not a valid `JSONNumber` without sign, i.e. `0`, `1.5` or `2e10`.
+fn validate_json_number(raw: &str, span: &Span) -> Result<()> {
+    fn digits(bytes: &[u8]) -> usize {
+        bytes.iter().take_while(|b| b.is_ascii_digit()).count()
+    }
+
+    let bytes = raw.as_bytes();
+    let mut pos = match bytes {
+        [b'0', ..] => 1,
+        [b'1'..=b'9', ..] => digits(bytes),
+        _ => 0,
+    };
+
+    let mut valid = pos > 0;
+    if valid && bytes.get(pos) == Some(&b'.') {
+        let fraction = digits(&bytes[pos + 1..]);
+        valid = fraction > 0;
+        pos += 1 + fraction;
+    }
+
+    if valid && matches!(bytes.get(pos), Some(b'e' | b'E')) {
+        pos += 1;
+        if matches!(bytes.get(pos), Some(b'+' | b'-')) {
+            pos += 1;
+        }
+
+        let exponent = digits(&bytes[pos..]);
+        valid = exponent > 0;
+        pos += exponent;
+    }
+
+    if !valid || pos != bytes.len() {
+        return Err(Error::syntax_error(
+            "Invalid number in JSON".to_owned(),
+            span.clone(),
+        ));
+    }
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::result_large_err)]
+
 extern crate core;
 extern crate serde;
 
@@ -11,6 +13,7 @@ mod cover;
 mod expr;
 mod function;
 mod iteration;
+mod json;
 mod literal;
 mod member_access;
 mod method;
@@ -18,18 +21,22 @@ mod module;
 mod static_semantics;
 mod stmt;
 mod variable;
+pub mod warning;
 
-use crate::error::{Error, Result};
-use crate::static_semantics::DirectivePrologueSemantics;
+use crate::error::{Error, ErrorKind, Result};
+use crate::static_semantics::{DirectivePrologueSemantics, IdentSemantics, StmtListSemantics};
+use crate::warning::{Warning, WarningKind, Warnings};
 use fajt_ast::{
-    Expr, ExprLiteral, Ident, LitString, Literal, Program, PropertyName, SourceType, Span, Stmt,
-    StmtExpr, StmtList,
+    Directive, Expr, ExprLiteral, Ident, LitString, Literal, Program, PropertyName, SourceType,
+    Span, Stmt, StmtList,
 };
 use fajt_common::io::{PeekRead, PeekReader, ReReadWithState};
 use fajt_lexer::error::ErrorKind as LexerErrorKind;
 use fajt_lexer::token::{KeywordContext, Token, TokenValue};
 use fajt_lexer::{punct, Lexer};
 use fajt_lexer::{token_matches, LexerState};
+use std::cell::RefCell;
+use std::rc::Rc;
 
 /// Similar trait to bool.then, but handles closures returning `Result`.
 pub trait ThenTry {
@@ -51,26 +58,258 @@ impl ThenTry for bool {
     }
 }
 
+/// Parse source as a script, i.e. sloppy mode unless a `"use strict"` directive is present.
 pub fn parse_script(source: &str) -> Result<Program> {
     parse::<Program>(source, SourceType::Script)
 }
 
+/// Parse source as a module, which is always strict mode code and allows top level `await`.
 pub fn parse_module(source: &str) -> Result<Program> {
     parse::<Program>(source, SourceType::Module)
 }
 
-/// Parse source into `Program` when type of source is known.
+/// Parse source as a module if it is not a valid script, i.e. when it contains top level `import`
+/// or `export` declarations. See `SourceType::Unknown`.
+pub fn parse_program(source: &str) -> Result<Program> {
+    parse::<Program>(source, SourceType::Unknown)
+}
+
+/// Parse source as a single expression, i.e. `a + b`. Intended for parsing fragments of code
+/// without a surrounding program, the expression is parsed as part of a script with `in` allowed.
+///
+/// ```
+/// use fajt_ast::Expr;
+///
+/// let expr = fajt_parser::parse_expression("a + b").unwrap();
+/// assert!(matches!(expr, Expr::Binary(_)));
+///
+/// // Anything after the expression is an error.
+/// assert!(fajt_parser::parse_expression("a b").is_err());
+/// ```
+pub fn parse_expression(source: &str) -> Result<Expr> {
+    parse_fragment::<Expr>(source)
+}
+
+/// Parse source as a single statement or declaration, i.e. `let a = 1;`. The statement is parsed
+/// as part of a script.
+///
+/// ```
+/// use fajt_ast::Stmt;
+///
+/// let stmt = fajt_parser::parse_statement("let a = 1;").unwrap();
+/// assert!(matches!(stmt, Stmt::Variable(_)));
+///
+/// // Only a single statement is allowed.
+/// assert!(fajt_parser::parse_statement("a; b;").is_err());
+/// ```
+pub fn parse_statement(source: &str) -> Result<Stmt> {
+    parse_fragment::<Stmt>(source)
+}
+
+/// Parse source as JSON, i.e. only JSON values with double quoted strings and property names, and
+/// no comments or trailing commas. The result is the same `Expr` as parsing the source as a
+/// JavaScript expression.
+///
+/// ```
+/// use fajt_ast::Expr;
+///
+/// let expr = fajt_parser::parse_json(r#"{"a": [1, -2.5, true, null]}"#).unwrap();
+/// assert!(matches!(expr, Expr::Literal(_)));
+///
+/// // Valid JavaScript is not necessarily valid JSON.
+/// assert!(fajt_parser::parse_json("{a: 1}").is_err());
+/// assert!(fajt_parser::parse_json("[1, 2,]").is_err());
+/// ```
+pub fn parse_json(source: &str) -> Result<Expr> {
+    let lexer = Lexer::new(source)?;
+    let mut reader = PeekReader::new(lexer)?;
+    let mut parser = Parser::new(&mut reader, SourceType::Script)?;
+    parser.parse_json_text(source)
+}
+
+/// Parse source as a single `T` in a script, it is an error if the whole source is not consumed.
+fn parse_fragment<T>(source: &str) -> Result<T>
+where
+    T: Parse,
+{
+    let lexer = Lexer::new(source)?;
+    let mut reader = PeekReader::new(lexer)?;
+    let mut parser = Parser::new(&mut reader, SourceType::Script)?;
+
+    let result = T::parse(&mut parser)?;
+    if !parser.is_end() {
+        return Err(Error::unexpected_token(parser.current()?.clone()));
+    }
+
+    Ok(result)
+}
+
+/// Parse source into `T`, with `SourceType::Unknown` the source is parsed as a script and if that
+/// fails, as a module.
 pub fn parse<T>(source: &str, source_type: SourceType) -> Result<T>
 where
     T: Parse,
 {
-    let mut lexer = Lexer::new(source).unwrap();
-    if source_type == SourceType::Module {
+    parse_with_options(source, &ParseOptions::new(source_type))
+}
+
+/// Parse source into `T` like `parse`, but starting in the context described by `options`. This
+/// allows parsing fragments as if they were nested in, for example, a generator function.
+///
+/// ```
+/// use fajt_ast::{Expr, SourceType};
+/// use fajt_parser::{parse_with_options, ParseOptions};
+///
+/// let options = ParseOptions::new(SourceType::Script).with_yield(true);
+/// let expr = parse_with_options::<Expr>("yield x", &options).unwrap();
+/// assert!(matches!(expr, Expr::Yield(_)));
+/// ```
+pub fn parse_with_options<T>(source: &str, options: &ParseOptions) -> Result<T>
+where
+    T: Parse,
+{
+    parse_with_warnings(source, options).map(|(result, _)| result)
+}
+
+/// Parse source into `T` like `parse_with_options`, also returning the warnings found while
+/// parsing, see `WarningKind`.
+///
+/// ```
+/// use fajt_ast::{Program, SourceType};
+/// use fajt_parser::warning::WarningKind;
+/// use fajt_parser::{parse_with_warnings, ParseOptions};
+///
+/// let options = ParseOptions::new(SourceType::Script);
+/// let (_, warnings) = parse_with_warnings::<Program>("with (a) {}", &options).unwrap();
+/// assert_eq!(warnings[0].kind(), &WarningKind::WithStatement);
+/// ```
+pub fn parse_with_warnings<T>(source: &str, options: &ParseOptions) -> Result<(T, Vec<Warning>)>
+where
+    T: Parse,
+{
+    if options.source_type == SourceType::Unknown {
+        return parse_unknown_source_type(source, options);
+    }
+
+    let mut lexer = Lexer::new(source)?;
+    lexer.set_interning(options.intern_identifiers);
+    if options.source_type == SourceType::Module {
         lexer.set_state(LexerState::default().with_html_comments_allowed(false));
     };
 
-    let mut reader = PeekReader::new(lexer).unwrap();
-    Parser::parse::<T>(&mut reader, source_type)
+    let mut reader = PeekReader::new(lexer)?;
+    let mut parser = Parser::new(&mut reader, options.source_type)?;
+    parser.context = options.seed(&parser.context);
+    let result = T::parse(&mut parser)?;
+    Ok((result, parser.take_warnings()))
+}
+
+/// Options for `parse_with_options`. The production parameters enabled here are set from the
+/// start of parsing, in addition to the ones implied by the source type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParseOptions {
+    source_type: SourceType,
+    is_await: bool,
+    is_yield: bool,
+    is_strict: bool,
+    is_return: bool,
+    intern_identifiers: bool,
+}
+
+impl ParseOptions {
+    pub fn new(source_type: SourceType) -> Self {
+        ParseOptions {
+            source_type,
+            is_await: false,
+            is_yield: false,
+            is_strict: false,
+            is_return: false,
+            intern_identifiers: false,
+        }
+    }
+
+    /// Parse as if inside an async function, `await` is an operator instead of an identifier.
+    pub fn with_await(self, is_await: bool) -> Self {
+        ParseOptions { is_await, ..self }
+    }
+
+    /// Parse as if inside a generator function, `yield` is an operator instead of an identifier.
+    pub fn with_yield(self, is_yield: bool) -> Self {
+        ParseOptions { is_yield, ..self }
+    }
+
+    /// Parse as strict mode code, even without a `"use strict"` directive.
+    pub fn with_strict(self, is_strict: bool) -> Self {
+        ParseOptions { is_strict, ..self }
+    }
+
+    /// Parse as if inside a function body, where `return` statements are allowed.
+    pub fn with_return(self, is_return: bool) -> Self {
+        ParseOptions { is_return, ..self }
+    }
+
+    /// Intern identifiers, so identifiers with the same name share one allocation. This reduces
+    /// allocations for large sources where the same names are used many times.
+    pub fn with_interned_identifiers(self, intern_identifiers: bool) -> Self {
+        ParseOptions {
+            intern_identifiers,
+            ..self
+        }
+    }
+
+    pub fn source_type(&self) -> SourceType {
+        self.source_type
+    }
+
+    fn with_source_type(self, source_type: SourceType) -> Self {
+        ParseOptions {
+            source_type,
+            ..self
+        }
+    }
+
+    /// Returns `context` with the production parameters of these options enabled.
+    fn seed(&self, context: &Context) -> Context {
+        Context {
+            is_await: context.is_await || self.is_await,
+            is_yield: context.is_yield || self.is_yield,
+            is_strict: context.is_strict || self.is_strict,
+            is_return: context.is_return || self.is_return,
+            ..context.clone()
+        }
+    }
+}
+
+/// Detects the source type by parsing `source` as a script. It is only parsed again as a module if
+/// the script failed because of syntax that is only valid in modules, i.e. an `import` or `export`
+/// declaration, or possibly a top level `await`.
+///
+/// If the script failed on `import` or `export` the module error is returned, otherwise the
+/// script error.
+fn parse_unknown_source_type<T>(source: &str, options: &ParseOptions) -> Result<(T, Vec<Warning>)>
+where
+    T: Parse,
+{
+    let script_options = options.with_source_type(SourceType::Script);
+    let script_error = match parse_with_warnings::<T>(source, &script_options) {
+        Ok(script) => return Ok(script),
+        Err(error) => error,
+    };
+
+    let is_module_declaration =
+        matches!(script_error.kind(), ErrorKind::ModuleDeclarationInScript(_));
+
+    // A top level `await` is an identifier in scripts, it fails anywhere after the keyword.
+    if !is_module_declaration && !source.contains("await") {
+        return Err(script_error);
+    }
+
+    let module_options = options.with_source_type(SourceType::Module);
+    match parse_with_warnings::<T>(source, &module_options) {
+        Ok(module) => Ok(module),
+        Err(module_error) if is_module_declaration => Err(module_error),
+        Err(_) => Err(script_error),
+    }
 }
 
 /// Context of the parser.
@@ -102,6 +341,15 @@ pub struct Context {
 
     /// `true` if static methods are allowed.
     static_method_allowed: bool,
+
+    /// `true` if we are inside formal parameters, where yield and await expressions are not allowed.
+    in_parameters: bool,
+
+    /// `true` if we are inside an iteration statement, where `break` and `continue` are allowed.
+    in_iteration: bool,
+
+    /// `true` if we are inside a switch statement, where `break` is allowed.
+    in_switch: bool,
 }
 
 macro_rules! modifier {
@@ -127,6 +375,9 @@ impl Context {
     modifier!(with_in_method: in_method);
     modifier!(with_super_call_allowed: super_call_allowed);
     modifier!(with_static_method_allowed: static_method_allowed);
+    modifier!(with_in_parameters: in_parameters);
+    modifier!(with_in_iteration: in_iteration);
+    modifier!(with_in_switch: in_switch);
 
     /// Resets all production parameters.
     fn reset_parameters(&self) -> Self {
@@ -170,7 +421,7 @@ impl Parse for Expr {
         I: ReReadWithState<Token, State = LexerState, Error = fajt_lexer::error::Error>,
     {
         parser
-            .with_context(Context::default().with_in(true))
+            .with_context(parser.context.with_in(true))
             .parse_expr()
     }
 }
@@ -181,7 +432,9 @@ impl Parse for Stmt {
         I: PeekRead<Token, Error = fajt_lexer::error::Error>,
         I: ReReadWithState<Token, State = LexerState, Error = fajt_lexer::error::Error>,
     {
-        parser.parse_declaration_or_statement()
+        let stmt = parser.parse_declaration_or_statement()?;
+        std::slice::from_ref(&stmt).early_errors_undefined_targets()?;
+        Ok(stmt)
     }
 }
 
@@ -203,6 +456,7 @@ impl Parse for Program {
         } else {
             parser.parse_all_stmts()?
         };
+        body.early_errors_undefined_targets()?;
 
         let span = parser.span_from(span_start);
         let stmt_list = StmtList {
@@ -215,6 +469,78 @@ impl Parse for Program {
     }
 }
 
+/// Iterator over the top level statements of a program, parsing one statement at a time. Created
+/// with `Parser::stmts`.
+///
+/// The directive prologue is parsed before the first statement and is not part of the iterator,
+/// see `StmtIter::directives`. Iteration ends after the first error.
+pub struct StmtIter<'p, 'a, I>
+where
+    I: PeekRead<Token, Error = fajt_lexer::error::Error>,
+{
+    parser: &'p mut Parser<'a, I>,
+    directives: Option<Vec<Directive>>,
+    done: bool,
+}
+
+impl<I> StmtIter<'_, '_, I>
+where
+    I: PeekRead<Token, Error = fajt_lexer::error::Error>,
+    I: ReReadWithState<Token, State = LexerState, Error = fajt_lexer::error::Error>,
+{
+    /// Directives of the program, `None` until the first statement is parsed.
+    pub fn directives(&self) -> Option<&[Directive]> {
+        self.directives.as_deref()
+    }
+
+    fn parse_next(&mut self) -> Result<Option<Stmt>> {
+        if self.directives.is_none() {
+            let directives = self.parser.parse_directive_prologue()?;
+            if directives.as_slice().contains_strict() {
+                self.parser.context = self.parser.context.with_strict(true);
+            }
+            self.directives = Some(directives);
+        }
+
+        if self.parser.is_end() {
+            return Ok(None);
+        }
+
+        let stmt = self.parser.parse_declaration_or_statement()?;
+        std::slice::from_ref(&stmt).early_errors_undefined_targets()?;
+        Ok(Some(stmt))
+    }
+}
+
+impl<I> Iterator for StmtIter<'_, '_, I>
+where
+    I: PeekRead<Token, Error = fajt_lexer::error::Error>,
+    I: ReReadWithState<Token, State = LexerState, Error = fajt_lexer::error::Error>,
+{
+    type Item = Result<Stmt>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.parse_next().transpose();
+        self.done = !matches!(result, Some(Ok(_)));
+        result
+    }
+}
+
+/// Default maximum nesting depth, see `Parser::set_max_depth`. Well above what hand written code
+/// uses, e.g. a hundred nested callbacks use a few hundred levels.
+pub const DEFAULT_MAX_DEPTH: usize = 1000;
+
+/// The stack is grown when less than this is left when entering a nesting level. Must be more than
+/// the stack used between two nesting levels, which is a lot more in a debug build.
+const STACK_RED_ZONE: usize = 1024 * 1024;
+
+/// Size of the stack allocated when the stack is grown.
+const STACK_GROWTH: usize = 8 * 1024 * 1024;
+
 pub struct Parser<'a, I>
 where
     I: PeekRead<Token, Error = fajt_lexer::error::Error>,
@@ -222,6 +548,9 @@ where
     context: Context,
     reader: &'a mut PeekReader<Token, I>,
     source_type: SourceType,
+    depth: usize,
+    max_depth: usize,
+    warnings: Rc<RefCell<Warnings>>,
 }
 
 impl<'a, I> Parser<'a, I>
@@ -229,14 +558,66 @@ where
     I: PeekRead<Token, Error = fajt_lexer::error::Error>,
     I: ReReadWithState<Token, State = LexerState, Error = fajt_lexer::error::Error>,
 {
+    /// Creates a parser for `source_type`, `SourceType::Unknown` is parsed as a script since the
+    /// parser can not change source type once started, see `parse` for detection.
     pub fn new(reader: &'a mut PeekReader<Token, I>, source_type: SourceType) -> Result<Self> {
+        let (source_type, context) = Self::initial_context(source_type);
         Ok(Parser {
-            context: Context::default(),
+            context,
             reader,
             source_type,
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+            warnings: Rc::default(),
         })
     }
 
+    /// Resets the parser to parse from `inner`, reusing the reader. Everything except the maximum
+    /// nesting depth is reset to the state of a new parser.
+    ///
+    /// The parser does not configure `inner`, i.e. a lexer for a module must disallow HTML-like
+    /// comments, see `parse`.
+    pub fn reset(&mut self, inner: I, source_type: SourceType) -> Result<()> {
+        self.reader.reset(inner)?;
+
+        let (source_type, context) = Self::initial_context(source_type);
+        self.source_type = source_type;
+        self.context = context;
+        self.depth = 0;
+        self.warnings.borrow_mut().take();
+        Ok(())
+    }
+
+    fn initial_context(source_type: SourceType) -> (SourceType, Context) {
+        let source_type = if source_type == SourceType::Unknown {
+            SourceType::Script
+        } else {
+            source_type
+        };
+
+        let context = if source_type == SourceType::Module {
+            Context::default().with_strict(true).with_await(true)
+        } else {
+            Context::default()
+        };
+
+        (source_type, context)
+    }
+
+    /// Sets the maximum nesting depth, input nested deeper than this results in an error.
+    ///
+    /// Each block, function and class body, statement nested in another statement, and each
+    /// parenthesized, bracketed or braced expression is one level. So is the right hand side of
+    /// operators that nest to the right, e.g. `a = b = c` and `a ? b : c ? d : e`. Prefix
+    /// operators and `else if` are parsed without recursion, but a chain of them is as deep in the
+    /// tree and counts one level per link.
+    ///
+    /// The stack is grown as needed while parsing, so this limits memory and the depth of the
+    /// resulting tree rather than protects the stack.
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth;
+    }
+
     pub fn parse<T>(reader: &'a mut PeekReader<Token, I>, source_type: SourceType) -> Result<T>
     where
         T: Parse,
@@ -245,6 +626,38 @@ where
         T::parse(&mut parser)
     }
 
+    /// Returns an iterator that lazily parses the top level statements, an alternative to parsing
+    /// a whole `Program` at once.
+    ///
+    /// ```
+    /// use fajt_common::io::PeekReader;
+    /// use fajt_lexer::Lexer;
+    /// use fajt_parser::Parser;
+    /// use fajt_ast::SourceType;
+    ///
+    /// let lexer = Lexer::new("a; b;").unwrap();
+    /// let mut reader = PeekReader::new(lexer).unwrap();
+    /// let mut parser = Parser::new(&mut reader, SourceType::Script).unwrap();
+    /// assert_eq!(parser.stmts().count(), 2);
+    /// ```
+    pub fn stmts(&mut self) -> StmtIter<'_, 'a, I> {
+        StmtIter {
+            parser: self,
+            directives: None,
+            done: false,
+        }
+    }
+
+    /// Returns the warnings found since the parser was created or the warnings were last taken.
+    pub fn take_warnings(&mut self) -> Vec<Warning> {
+        self.warnings.borrow_mut().take()
+    }
+
+    /// Adds a warning, unless the same warning was already added when tokens were re-read.
+    fn warn(&self, kind: WarningKind, span: Span) {
+        self.warnings.borrow_mut().add(Warning::new(kind, span));
+    }
+
     fn current(&self) -> Result<&Token> {
         Ok(self.reader.current()?)
     }
@@ -253,6 +666,25 @@ where
         Ok(self.reader.consume()?)
     }
 
+    /// Returns error if `token` is a legacy octal number or a string with a legacy octal escape
+    /// in strict mode code, in sloppy mode a warning is added instead.
+    fn validate_legacy_octal(&self, token: &Token) -> Result<()> {
+        if !token.legacy_octal {
+            return Ok(());
+        }
+
+        if self.context.is_strict {
+            return Err(legacy_octal_error(token));
+        }
+
+        let kind = match token.value {
+            TokenValue::Literal(Literal::Number(_)) => WarningKind::LegacyOctalLiteral,
+            _ => WarningKind::LegacyOctalEscape,
+        };
+        self.warn(kind, token.span.clone());
+        Ok(())
+    }
+
     fn peek(&self) -> Option<&Token> {
         self.reader.peek().ok()
     }
@@ -261,7 +693,7 @@ where
         self.reader
             .current()
             .err()
-            .map_or(false, |error| error.kind() == &LexerErrorKind::EndOfStream)
+            .is_some_and(|error| error.kind() == &LexerErrorKind::EndOfStream)
     }
 
     fn position(&self) -> usize {
@@ -281,9 +713,35 @@ where
             context,
             reader: self.reader,
             source_type: self.source_type,
+            depth: self.depth,
+            max_depth: self.max_depth,
+            warnings: self.warnings.clone(),
         }
     }
 
+    /// Runs `parse` one nesting level deeper, errors if the maximum nesting depth is exceeded.
+    /// The stack is grown first if little is left.
+    fn nested<T, F>(&mut self, parse: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        self.check_depth(0)?;
+
+        self.depth += 1;
+        let result = grow_stack(|| parse(self));
+        self.depth -= 1;
+        result
+    }
+
+    /// Errors if `levels` more nesting levels than current exceeds the maximum nesting depth.
+    fn check_depth(&self, levels: usize) -> Result<()> {
+        if self.depth + levels >= self.max_depth {
+            return Err(Error::nesting_too_deep(self.current()?.span.clone()));
+        }
+
+        Ok(())
+    }
+
     /// Returns `true` if current token matches `value`.
     fn current_matches(&self, value: &TokenValue) -> bool {
         if let Ok(token) = self.current() {
@@ -350,12 +808,12 @@ where
 
     /// Returns `true` if current token is followed by new line.
     fn followed_by_new_line(&self) -> bool {
-        self.peek().map_or(false, |t| t.first_on_line)
+        self.peek().is_some_and(|t| t.first_on_line)
     }
 
     /// Returns `true` if current token is followed by new line.
     fn first_on_line(&self) -> bool {
-        self.current().map_or(false, |t| t.first_on_line)
+        self.current().is_ok_and(|t| t.first_on_line)
     }
 
     /// Returns `true` if next token could be parsed to a valid identifier.
@@ -396,6 +854,14 @@ where
         })
     }
 
+    /// Parses the `BindingIdentifier` production, same as `parse_identifier` but also applies the
+    /// early errors for identifiers being bound.
+    fn parse_binding_identifier(&mut self) -> Result<Ident> {
+        let ident = self.parse_identifier()?;
+        ident.early_errors_binding_identifier(&self.context)?;
+        Ok(ident)
+    }
+
     /// Parses the `IdentifierName` production, this do not respect the reserved words.
     fn parse_identifier_name(&mut self) -> Result<Ident> {
         let token = self.consume()?;
@@ -411,6 +877,7 @@ where
         match self.current()? {
             token_matches!(@literal) => {
                 let token = self.consume()?;
+                self.validate_legacy_octal(&token)?;
                 match token.value {
                     TokenValue::Literal(Literal::String(string)) => {
                         Ok(PropertyName::String(string))
@@ -423,7 +890,11 @@ where
             }
             token_matches!(punct!("[")) => {
                 self.consume()?;
-                let expr = self.parse_assignment_expr()?;
+                let expr = self.nested(|parser| {
+                    parser
+                        .with_context(parser.context.with_in(true))
+                        .parse_assignment_expr()
+                })?;
                 self.consume_assert(&punct!("]"))?;
                 Ok(PropertyName::Computed(expr.into()))
             }
@@ -434,8 +905,11 @@ where
         }
     }
 
-    fn parse_directive_prologue(&mut self) -> Result<Vec<LitString>> {
+    /// Parses the `DirectivePrologue`, the string literal expression statements at the start of a
+    /// script, module or function body.
+    fn parse_directive_prologue(&mut self) -> Result<Vec<Directive>> {
         let mut directives = Vec::new();
+        let mut legacy_octal = None;
 
         loop {
             if !self.current_matches_string_literal() {
@@ -443,25 +917,36 @@ where
             }
 
             let stmt_start_token = self.current()?.clone();
-            let string_literal = match self.parse_declaration_or_statement()? {
-                Stmt::Expr(StmtExpr { expr, .. }) => match *expr {
-                    Expr::Literal(ExprLiteral {
-                        literal: Literal::String(string),
-                        ..
-                    }) => Some(string),
-                    _ => None,
-                },
-                _ => None,
-            };
-
-            if let Some(string) = string_literal {
-                directives.push(string);
+            let directive = self
+                .parse_declaration_or_statement()?
+                .try_as_expr_stmt()
+                .and_then(|stmt| stmt.expr.try_as_literal())
+                .and_then(|ExprLiteral { span, literal }| {
+                    Some(Directive {
+                        span,
+                        value: literal.try_as_string()?,
+                        escaped: stmt_start_token.escaped,
+                    })
+                });
+
+            if let Some(directive) = directive {
+                if stmt_start_token.legacy_octal && legacy_octal.is_none() {
+                    legacy_octal = Some(stmt_start_token);
+                }
+                directives.push(directive);
             } else {
                 self.reader.rewind_to(&stmt_start_token)?;
                 break;
             }
         }
 
+        // Directives before `"use strict"` are parsed as sloppy mode code.
+        if let Some(token) = legacy_octal {
+            if directives.as_slice().contains_strict() {
+                return Err(legacy_octal_error(&token));
+            }
+        }
+
         Ok(directives)
     }
 
@@ -496,6 +981,20 @@ where
     }
 }
 
+/// Runs `f`, on a newly allocated stack if little is left of the current one. Used for everything
+/// recursing once per nesting level, see `Parser::set_max_depth`.
+fn grow_stack<T>(f: impl FnOnce() -> T) -> T {
+    stacker::maybe_grow(STACK_RED_ZONE, STACK_GROWTH, f)
+}
+
+fn legacy_octal_error(token: &Token) -> Error {
+    let message = match token.value {
+        TokenValue::Literal(Literal::Number(_)) => "Octal literals are not allowed in strict mode",
+        _ => "Octal escape sequences are not allowed in strict mode",
+    };
+    Error::syntax_error(message.to_owned(), token.span.clone())
+}
+
 /// Returns `true` if provided `token` could be parsed to a valid identifier, and not a reserved
 /// word in provided context.
 fn is_identifier(token: Option<&Token>, keyword_context: KeywordContext) -> bool {
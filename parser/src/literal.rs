@@ -28,6 +28,7 @@ where
     /// Parses the `Literal` production.
     pub(super) fn parse_literal(&mut self) -> Result<Expr> {
         let token = self.consume()?;
+        self.validate_legacy_octal(&token)?;
         if let TokenValue::Literal(literal) = token.value {
             Ok(ExprLiteral {
                 span: token.span,
@@ -75,7 +76,12 @@ where
         parts: &mut Vec<TemplatePart>,
     ) -> Result<()> {
         loop {
-            parts.push(TemplatePart::Expr(Box::new(self.parse_expr()?)));
+            let expr = self.nested(|parser| {
+                parser
+                    .with_context(parser.context.with_in(true))
+                    .parse_expr()
+            })?;
+            parts.push(TemplatePart::Expr(Box::new(expr)));
             self.reader
                 .reread_with_state(LexerState::inside_template())?;
 
@@ -120,6 +126,10 @@ where
 
     /// Parses the `ArrayLiteral` production.
     pub(super) fn parse_array_literal(&mut self) -> Result<Expr> {
+        self.nested(Self::parse_nested_array_literal)
+    }
+
+    fn parse_nested_array_literal(&mut self) -> Result<Expr> {
         let span_start = self.position();
         self.consume_assert(&punct!("["))?;
 
@@ -129,7 +139,9 @@ where
                 break;
             }
 
-            let element = self.parse_array_element()?;
+            let element = self
+                .with_context(self.context.with_in(true))
+                .parse_array_element()?;
             if element != ArrayElement::Elision {
                 self.consume_list_delimiter(&punct!("]"))?;
             }
@@ -151,11 +163,7 @@ where
                 self.consume()?;
                 Ok(ArrayElement::Elision)
             }
-            token_matches!(punct!("...")) => {
-                self.consume()?;
-                let expr = self.parse_assignment_expr()?;
-                Ok(ArrayElement::Spread(expr))
-            }
+            token_matches!(punct!("...")) => Ok(ArrayElement::Spread(self.parse_spread()?)),
             _ => {
                 let expr = self.parse_assignment_expr()?;
                 Ok(ArrayElement::Expr(expr))
@@ -165,6 +173,10 @@ where
 
     /// Parses the `ObjectLiteral` production.
     pub(super) fn parse_object_literal(&mut self) -> Result<Expr> {
+        self.nested(Self::parse_nested_object_literal)
+    }
+
+    fn parse_nested_object_literal(&mut self) -> Result<Expr> {
         let span_start = self.position();
         self.consume_assert(&punct!("{"))?;
 
@@ -174,7 +186,9 @@ where
                 break;
             }
 
-            let prop = self.parse_property_definition()?;
+            let prop = self
+                .with_context(self.context.with_in(true))
+                .parse_property_definition()?;
 
             // This is covered by the `CoverInitializedName` production. It is invalid syntax in an
             // object literal.
@@ -202,11 +216,7 @@ where
     /// Parses the `PropertyDefinition` production.
     fn parse_property_definition(&mut self) -> Result<PropertyDefinition> {
         match self.current()? {
-            token_matches!(punct!("...")) => {
-                self.consume()?;
-                let expr = self.parse_assignment_expr()?;
-                Ok(PropertyDefinition::Spread(expr))
-            }
+            token_matches!(punct!("...")) => Ok(PropertyDefinition::Spread(self.parse_spread()?)),
             _ if self.peek_matches(&punct!(":")) => {
                 let span_start = self.position();
                 let name = self.parse_property_name()?;
@@ -238,7 +248,8 @@ where
         if self.current_matches(&punct!(":")) {
             self.parse_named_property_definition(span_start, name)
         } else {
-            self.parse_method(span_start, false, name, MethodKind::Method)
+            self.with_context(self.context.with_yield(false).with_await(false))
+                .parse_method(span_start, false, name, MethodKind::Method)
                 .map(PropertyDefinition::Method)
         }
     }
@@ -262,10 +273,24 @@ where
     pub fn is_object_method_definition(&self) -> bool {
         match self.current() {
             token_matches!(ok: punct!("*") | punct!("[")) => true,
-            token_matches!(ok: keyword!("static")) => true,
-            token_matches!(ok: keyword!("async")) if !self.peek_matches(&punct!(":")) => true,
-            token_matches!(ok: keyword!("get") | keyword!("set")) => true,
+            // `async` followed by a new line is a property named `async`, i.e. `{ async \n m() {} }`
+            // is the shorthand property `async` followed by a method without separating comma.
+            token_matches!(ok: keyword!("async")) if self.followed_by_new_line() => {
+                self.peek_matches(&punct!("("))
+            }
+            token_matches!(
+                ok: keyword!("static") | keyword!("async") | keyword!("get") | keyword!("set")
+            ) => !self.peek_ends_property_name(),
             _ => self.peek_matches(&punct!("(")),
         }
     }
+
+    /// Returns `true` if the next token ends a property name, i.e. the current token is the name
+    /// of a shorthand property, `{ get }`, or a named property, `{ get: a }`.
+    fn peek_ends_property_name(&self) -> bool {
+        matches!(
+            self.peek(),
+            token_matches!(opt: punct!(",") | punct!("}") | punct!(":") | punct!("="))
+        )
+    }
 }
@@ -148,7 +148,7 @@ where
 
     fn parse_computed_property(&mut self) -> Result<Expr> {
         self.consume_assert(&punct!("["))?;
-        let expr = self.parse_expr()?;
+        let expr = self.nested(Self::parse_expr)?;
         self.consume_assert(&punct!("]"))?;
 
         Ok(expr)
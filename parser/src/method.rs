@@ -27,13 +27,16 @@ where
             token_matches!(keyword!("set")) if !self.peek_matches(&punct!("(")) => {
                 self.parse_getter_or_setter(is_static, MethodKind::Set)
             }
-            token_matches!(keyword!("async")) if !self.followed_by_new_line() => {
+            token_matches!(keyword!("async"))
+                if !self.followed_by_new_line() && !self.peek_matches(&punct!("(")) =>
+            {
                 self.parse_async_method(is_static)
             }
             _ => {
                 let span_start = self.position();
                 let name = self.parse_property_name()?;
-                self.parse_method(span_start, is_static, name, MethodKind::Method)
+                self.with_context(self.context.with_yield(false).with_await(false))
+                    .parse_method(span_start, is_static, name, MethodKind::Method)
             }
         }
     }
@@ -57,7 +60,8 @@ where
         self.consume()?;
 
         let name = self.parse_property_name()?;
-        self.parse_method(span_start, is_static, name, kind)
+        self.with_context(self.context.with_yield(false).with_await(false))
+            .parse_method(span_start, is_static, name, kind)
     }
 
     /// Parses the `AsyncMethod` and `AsyncGeneratorMethod` production.
@@ -71,6 +75,8 @@ where
             .parse_method(span_start, is_static, name, MethodKind::Method)
     }
 
+    /// Parses the parameters and body of a method, the `Yield` and `Await` parameters of the
+    /// context must be set for the kind of method, they are not inherited from the surrounding code.
     pub(super) fn parse_method(
         &mut self,
         span_start: usize,
@@ -95,6 +101,7 @@ where
         let span = self.span_from(span_start);
         Ok(MethodDefinition {
             span,
+            decorators: Vec::new(),
             name,
             kind,
             parameters,
@@ -1,12 +1,15 @@
 use crate::error::Result;
+use crate::static_semantics::IdentSemantics;
 use crate::{Error, Parser, ThenTry};
 use fajt_ast::{
     DeclExport, DeclImport, ExportDecl, ExportDefaultDecl, ExportDefaultExpr, ExportNamed,
-    ExportNamespace, Ident, LitString, NamedExport, NamedImport, Stmt, VariableKind,
+    ExportNamespace, Ident, ImportAttribute, ImportAttributeKey, ImportAttributes,
+    ImportAttributesKeyword, LitString, Literal, ModuleExportName, NamedExport, NamedImport, Stmt,
+    VariableKind,
 };
 use fajt_common::io::{PeekRead, ReReadWithState};
 use fajt_lexer::punct;
-use fajt_lexer::token::Token;
+use fajt_lexer::token::{Token, TokenValue};
 use fajt_lexer::token_matches;
 use fajt_lexer::{keyword, LexerState};
 
@@ -28,7 +31,11 @@ where
             | token_matches!(ok: keyword!("const"))
             | token_matches!(ok: keyword!("function"))
             | token_matches!(ok: keyword!("class")) => self.parse_declaration_export(span_start),
-            token_matches!(ok: keyword!("async")) if self.peek_matches(&keyword!("function")) => {
+            #[cfg(feature = "decorators")]
+            token_matches!(ok: punct!("@")) => self.parse_declaration_export(span_start),
+            token_matches!(ok: keyword!("async"))
+                if self.peek_matches(&keyword!("function")) && !self.followed_by_new_line() =>
+            {
                 self.parse_declaration_export(span_start)
             }
             token_matches!(ok: keyword!("default")) => self.parse_default_export(span_start),
@@ -42,9 +49,16 @@ where
             token_matches!(keyword!("class")) | token_matches!(keyword!("function")) => self
                 .with_context(self.context.with_default(true))
                 .parse_declaration_default_export(span_start),
-            token_matches!(keyword!("async")) if self.peek_matches(&keyword!("function")) => self
+            #[cfg(feature = "decorators")]
+            token_matches!(punct!("@")) => self
                 .with_context(self.context.with_default(true))
                 .parse_declaration_default_export(span_start),
+            token_matches!(keyword!("async"))
+                if self.peek_matches(&keyword!("function")) && !self.followed_by_new_line() =>
+            {
+                self.with_context(self.context.with_default(true))
+                    .parse_declaration_default_export(span_start)
+            }
             _ => {
                 let expr = self
                     .with_context(self.context.with_in(true))
@@ -108,12 +122,19 @@ where
         self.consume_assert(&punct!("*"))?;
         let alias = self
             .maybe_consume(&keyword!("as"))?
-            .then_try(|| self.parse_identifier_name())?;
+            .then_try(|| self.parse_module_export_name())?;
         self.consume_assert(&keyword!("from"))?;
         let from = self.parse_module_specifier()?;
+        let attributes = self.parse_optional_with_clause()?;
         self.consume_optional_semicolon()?;
         let span = self.span_from(span_start);
-        Ok(DeclExport::Namespace(ExportNamespace { span, alias, from }).into())
+        Ok(DeclExport::Namespace(ExportNamespace {
+            span,
+            alias,
+            from,
+            attributes,
+        })
+        .into())
     }
 
     /// Parses `export { name }` and `export { name as name2 } from 'other'`.
@@ -122,12 +143,35 @@ where
         let from = self
             .maybe_consume(&keyword!("from"))?
             .then_try(|| self.parse_module_specifier())?;
+        let attributes = if from.is_some() {
+            self.parse_optional_with_clause()?
+        } else {
+            None
+        };
+
+        if from.is_none() {
+            // Without `from`, the exports refer to local bindings which can not be strings.
+            let string_export = named_exports.iter().find(|export| {
+                let local_name = export.alias_of.as_ref().unwrap_or(&export.name);
+                matches!(local_name, ModuleExportName::String(_))
+            });
+
+            if let Some(string_export) = string_export {
+                return Err(Error::syntax_error(
+                    "A string literal cannot be used as an exported binding without `from`"
+                        .to_owned(),
+                    string_export.span.clone(),
+                ));
+            }
+        }
+
         self.consume_optional_semicolon()?;
         let span = self.span_from(span_start);
         Ok(DeclExport::Named(ExportNamed {
             span,
             named_exports,
             from,
+            attributes,
         })
         .into())
     }
@@ -146,6 +190,7 @@ where
 
         self.consume_assert(&keyword!("from"))?;
         let from = self.parse_module_specifier()?;
+        let attributes = self.parse_optional_with_clause()?;
 
         self.consume_optional_semicolon()?;
 
@@ -156,13 +201,16 @@ where
             namespace_binding: import_clause.namespace_binding,
             named_imports: import_clause.named_imports,
             from,
+            attributes,
         }
         .into())
     }
 
     /// Parses the `ImportClause` production.
     fn parse_import_clause(&mut self) -> Result<ImportClause> {
-        let default_binding = self.is_identifier().then_try(|| self.parse_identifier())?;
+        let default_binding = self
+            .is_identifier()
+            .then_try(|| self.parse_binding_identifier())?;
         let mut clause = ImportClause {
             default_binding,
             namespace_binding: None,
@@ -186,6 +234,7 @@ where
 
     fn parse_module_import(&mut self, span_start: usize) -> Result<Stmt> {
         let from = self.parse_module_specifier()?;
+        let attributes = self.parse_optional_with_clause()?;
         self.consume_optional_semicolon()?;
 
         let span = self.span_from(span_start);
@@ -195,25 +244,100 @@ where
             namespace_binding: None,
             named_imports: None,
             from,
+            attributes,
         }
         .into())
     }
 
     /// Parses the `ModuleSpecifier` production.
     fn parse_module_specifier(&mut self) -> Result<LitString> {
-        let module_name = self
-            .parse_literal()?
-            .unwrap_literal()
-            .literal
-            .unwrap_string();
-        Ok(module_name)
+        let token = self.consume()?;
+        self.validate_legacy_octal(&token)?;
+        match token.value {
+            TokenValue::Literal(Literal::String(module_name)) => Ok(module_name),
+            _ => Err(Error::unexpected_token(token)),
+        }
+    }
+
+    /// Parses the `WithClause` production if the current token is `with`, or the legacy `assert`
+    /// on the same line.
+    fn parse_optional_with_clause(&mut self) -> Result<Option<Box<ImportAttributes>>> {
+        let keyword = match self.current() {
+            token_matches!(ok: keyword!("with")) => ImportAttributesKeyword::With,
+            token_matches!(ok: keyword!("assert")) if !self.first_on_line() => {
+                ImportAttributesKeyword::Assert
+            }
+            _ => return Ok(None),
+        };
+
+        let span_start = self.position();
+        self.consume()?;
+        self.consume_assert(&punct!("{"))?;
+
+        let mut attributes: Vec<ImportAttribute> = Vec::new();
+        loop {
+            if self.current_matches(&punct!("}")) {
+                self.consume()?;
+                break;
+            }
+
+            let attribute = self.parse_import_attribute()?;
+            let key = attribute.key.value();
+            if attributes.iter().any(|a| a.key.value() == key) {
+                return Err(Error::syntax_error(
+                    format!("Duplicate import attribute '{}'", key),
+                    attribute.span,
+                ));
+            }
+
+            attributes.push(attribute);
+            self.consume_list_delimiter(&punct!("}"))?;
+        }
+
+        let span = self.span_from(span_start);
+        Ok(Some(Box::new(ImportAttributes {
+            span,
+            keyword,
+            attributes,
+        })))
+    }
+
+    /// Parses the `AttributeEntry` production.
+    fn parse_import_attribute(&mut self) -> Result<ImportAttribute> {
+        let span_start = self.position();
+        let key = if self.current_matches_string_literal() {
+            ImportAttributeKey::String(self.parse_module_specifier()?)
+        } else {
+            ImportAttributeKey::Ident(self.parse_identifier_name()?)
+        };
+
+        self.consume_assert(&punct!(":"))?;
+        if !self.current_matches_string_literal() {
+            return Err(Error::syntax_error(
+                "Import attribute value must be a string literal".to_owned(),
+                self.current()?.span.clone(),
+            ));
+        }
+
+        let value = self.parse_module_specifier()?;
+        let span = self.span_from(span_start);
+        Ok(ImportAttribute { span, key, value })
+    }
+
+    /// Parses the `ModuleExportName` production.
+    fn parse_module_export_name(&mut self) -> Result<ModuleExportName> {
+        if self.current_matches_string_literal() {
+            Ok(ModuleExportName::String(self.parse_module_specifier()?))
+        } else {
+            Ok(ModuleExportName::Ident(self.parse_identifier_name()?))
+        }
     }
 
     /// Parses the `NameSpaceImport` production.
     fn parse_namespace_import(&mut self) -> Result<Ident> {
         self.consume_assert(&punct!("*"))?;
         self.consume_assert(&keyword!("as"))?;
-        self.parse_identifier()
+        self.parse_binding_identifier()
     }
 
     /// Parses the `NamedImports` production.
@@ -237,11 +361,26 @@ where
     /// Parses the `ImportSpecifier` production.
     fn parse_import_specifier(&mut self) -> Result<NamedImport> {
         let span_start = self.position();
-        let name = self.parse_identifier_name()?;
+        let name = self.parse_module_export_name()?;
         let alias = self
             .maybe_consume(&keyword!("as"))?
-            .then_try(|| self.parse_identifier())?;
+            .then_try(|| self.parse_binding_identifier())?;
+
         let span = self.span_from(span_start);
+        if alias.is_none() {
+            match &name {
+                ModuleExportName::Ident(name) => {
+                    name.early_errors_binding_identifier(&self.context)?
+                }
+                ModuleExportName::String(_) => {
+                    return Err(Error::syntax_error(
+                        "A string literal cannot be used as an imported binding".to_owned(),
+                        span,
+                    ));
+                }
+            }
+        }
+
         Ok(NamedImport { span, name, alias })
     }
 
@@ -265,13 +404,13 @@ where
     /// Parses the `ExportSpecifier` production.
     fn parse_export_specifier(&mut self) -> Result<NamedExport> {
         let span_start = self.position();
-        let mut name = self.parse_identifier_name()?;
+        let mut name = self.parse_module_export_name()?;
 
         // If there is an alias, we swap the name and alias identifiers, since the name should be
         // the name of the export, and the alias the local name.
         let alias_of = self
             .maybe_consume(&keyword!("as"))?
-            .then_try(|| self.parse_identifier_name())?
+            .then_try(|| self.parse_module_export_name())?
             .map(|alias| std::mem::replace(&mut name, alias));
 
         let span = self.span_from(span_start);
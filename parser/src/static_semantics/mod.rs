@@ -2,8 +2,11 @@
 mod macros;
 
 use crate::error::Result;
-use crate::{Context, Error};
-use fajt_ast::{BindingPattern, Expr, FormalParameters, LitString, Spanned};
+use crate::{grow_stack, Context, Error};
+use fajt_ast::{
+    BindingPattern, Directive, Expr, FormalParameters, Ident, ObjectBindingProp, Spanned, Stmt,
+    StmtBreak, StmtContinue, StmtDoWhile, StmtFor, StmtForIn, StmtForOf, StmtWhile,
+};
 
 impl_trait!(
     impl trait ExprSemantics for Expr {
@@ -11,14 +14,8 @@ impl_trait!(
         fn is_assignment_target_type_simple(&self, context: &Context) -> Result<bool> {
             Ok(match self {
                 Expr::IdentRef(ident) => {
-                    if context.is_strict && (ident.name == "arguments" || ident.name == "eval") {
-                        return Err(Error::syntax_error(
-                            "Unexpected `eval` or `arguments` in strict mode".to_owned(),
-                            self.span().clone(),
-                        ));
-                    } else {
-                        true
-                    }
+                    ident.early_errors_binding_identifier(context)?;
+                    true
                 }
                 Expr::Member(_) => true,
                 Expr::Parenthesized(parenthesized) => {
@@ -77,9 +74,26 @@ impl_trait!(
 );
 
 impl_trait!(
-    impl trait DirectivePrologueSemantics for &[LitString] {
+    impl trait IdentSemantics for Ident {
+        /// Early errors for identifiers that are bound or assigned to, `eval` and `arguments` are
+        /// not allowed in strict mode.
+        fn early_errors_binding_identifier(&self, context: &Context) -> Result<()> {
+            if context.is_strict && (self.name == "arguments" || self.name == "eval") {
+                return Err(Error::syntax_error(
+                    "Unexpected `eval` or `arguments` in strict mode".to_owned(),
+                    self.span.clone(),
+                ));
+            }
+
+            Ok(())
+        }
+    }
+);
+
+impl_trait!(
+    impl trait DirectivePrologueSemantics for &[Directive] {
         fn contains_strict(&self) -> bool {
-            self.iter().any(|s| s.value == "use strict")
+            self.iter().any(Directive::is_use_strict)
         }
     }
 );
@@ -91,16 +105,51 @@ impl_trait!(
                 BindingPattern::Ident(ident) => {
                     vec![ident.name.as_ref()]
                 }
-                BindingPattern::Array(_arr) => {
-                    // TODO
-                    vec![]
+                BindingPattern::Array(array) => {
+                    let mut names: Vec<&str> = array
+                        .elements
+                        .iter()
+                        .flatten()
+                        .flat_map(|element| element.pattern.get_bound_names())
+                        .collect();
+
+                    if let Some(rest) = &array.rest {
+                        names.append(&mut rest.get_bound_names());
+                    }
+
+                    names
                 }
-                BindingPattern::Object(_obj) => {
-                    // TODO
-                    vec![]
+                BindingPattern::Object(object) => {
+                    let mut names: Vec<&str> = object
+                        .props
+                        .iter()
+                        .flat_map(|prop| match prop {
+                            ObjectBindingProp::Single(single) => vec![single.ident.name.as_ref()],
+                            ObjectBindingProp::Named(named) => named.binding.pattern.get_bound_names(),
+                        })
+                        .collect();
+
+                    if let Some(rest) = &object.rest {
+                        names.append(&mut rest.get_bound_names());
+                    }
+
+                    names
                 }
             }
         }
+
+        /// Early error for `LexicalDeclaration` and `ForDeclaration`, `let` can not be bound by
+        /// `let` or `const`.
+        fn early_errors_lexical_binding(&self) -> Result<()> {
+            if self.get_bound_names().contains(&"let") {
+                return Err(Error::syntax_error(
+                    "`let` is not allowed as a lexically bound name".to_owned(),
+                    self.span().clone(),
+                ));
+            }
+
+            Ok(())
+        }
     }
 );
 
@@ -149,9 +198,13 @@ impl_trait!(
             Ok(())
         }
 
-        fn early_errors_method(&self, body_directives: &[LitString]) -> Result<()> {
+        fn early_errors_method(&self, body_directives: &[Directive]) -> Result<()> {
             self.early_errors_forbidden_use_strict(body_directives)?;
+            self.early_errors_unique()
+        }
 
+        /// Early errors for `UniqueFormalParameters`, i.e. parameters of methods and arrow functions.
+        fn early_errors_unique(&self) -> Result<()> {
             let mut bound_names = self.bound_names();
             bound_names.sort_unstable();
 
@@ -170,7 +223,7 @@ impl_trait!(
             Ok(())
         }
 
-        fn early_errors_setter(&self, body_directives: &[LitString]) -> Result<()> {
+        fn early_errors_setter(&self, body_directives: &[Directive]) -> Result<()> {
             self.early_errors_forbidden_use_strict(body_directives)?;
 
             if self.rest.is_some() {
@@ -190,7 +243,7 @@ impl_trait!(
             Ok(())
         }
 
-        fn early_errors_forbidden_use_strict(&self, body_directives: &[LitString]) -> Result<()> {
+        fn early_errors_forbidden_use_strict(&self, body_directives: &[Directive]) -> Result<()> {
             if !self.is_simple() && body_directives.contains_strict() {
                 return Err(Error::syntax_error(
                     "Only name parameters allowed in method with \"use strict\"".to_owned(),
@@ -203,6 +256,159 @@ impl_trait!(
     }
 );
 
+impl_trait!(
+    impl trait StmtListSemantics for [Stmt] {
+        /// Early errors for `break` and `continue` statements with labels that does not refer to an
+        /// enclosing labelled statement, see `ContainsUndefinedBreakTarget` and
+        /// `ContainsUndefinedContinueTarget`. Must be applied to function and script bodies.
+        fn early_errors_undefined_targets(&self) -> Result<()> {
+            let mut label_set = LabelSet::default();
+            self.iter()
+                .try_for_each(|stmt| early_errors_undefined_targets(stmt, &mut label_set, 0))
+        }
+    }
+);
+
+/// Labels in scope of a statement, innermost last. Labels are pushed when entering a labelled
+/// statement and popped when leaving it.
+#[derive(Default)]
+struct LabelSet<'a> {
+    labels: Vec<Label<'a>>,
+}
+
+struct Label<'a> {
+    name: &'a str,
+    /// `true` if the label is applied to an iteration statement, i.e. a valid `continue` target.
+    is_iteration: bool,
+}
+
+impl LabelSet<'_> {
+    fn contains(&self, name: &str) -> bool {
+        self.labels.iter().any(|label| label.name == name)
+    }
+
+    fn contains_iteration(&self, name: &str) -> bool {
+        self.labels
+            .iter()
+            .any(|label| label.is_iteration && label.name == name)
+    }
+}
+
+/// `current_labels` is the number of innermost labels directly applied to `stmt`, i.e. 2 for
+/// `a: b: stmt`.
+fn early_errors_undefined_targets<'a>(
+    stmt: &'a Stmt,
+    label_set: &mut LabelSet<'a>,
+    current_labels: usize,
+) -> Result<()> {
+    match stmt {
+        Stmt::Break(StmtBreak {
+            label: Some(label), ..
+        }) if !label_set.contains(&label.name) => {
+            return Err(undefined_label(label));
+        }
+        Stmt::Continue(StmtContinue {
+            label: Some(label), ..
+        }) => {
+            if !label_set.contains(&label.name) {
+                return Err(undefined_label(label));
+            }
+
+            if !label_set.contains_iteration(&label.name) {
+                return Err(Error::syntax_error(
+                    format!(
+                        "Illegal continue statement: '{}' does not denote an iteration statement",
+                        label.name
+                    ),
+                    label.span.clone(),
+                ));
+            }
+        }
+        Stmt::Labeled(labeled) => {
+            label_set.labels.push(Label {
+                name: &labeled.label.name,
+                is_iteration: false,
+            });
+            let result = grow_stack(|| {
+                early_errors_undefined_targets(&labeled.body, label_set, current_labels + 1)
+            });
+            label_set.labels.pop();
+            return result;
+        }
+        Stmt::DoWhile(StmtDoWhile { body, .. })
+        | Stmt::While(StmtWhile { body, .. })
+        | Stmt::For(StmtFor { body, .. })
+        | Stmt::ForIn(StmtForIn { body, .. })
+        | Stmt::ForOf(StmtForOf { body, .. }) => {
+            return early_errors_iteration_body(body, label_set, current_labels);
+        }
+        _ => {}
+    }
+
+    let mut check =
+        |stmt: &'a Stmt| grow_stack(|| early_errors_undefined_targets(stmt, label_set, 0));
+    match stmt {
+        Stmt::Block(block) => block.statements.iter().try_for_each(check)?,
+        Stmt::If(if_stmt) => {
+            // Chains of `else if` are checked in a loop, since they may be long.
+            let mut if_stmt = if_stmt;
+            loop {
+                check(&if_stmt.consequent)?;
+                match if_stmt.alternate.as_deref() {
+                    Some(Stmt::If(alternate)) => if_stmt = alternate,
+                    Some(alternate) => break check(alternate)?,
+                    None => break,
+                }
+            }
+        }
+        Stmt::With(with) => check(&with.body)?,
+        Stmt::Switch(switch) => switch
+            .cases
+            .iter()
+            .flat_map(|case| &case.consequent)
+            .try_for_each(check)?,
+        Stmt::Try(try_stmt) => {
+            try_stmt.block.statements.iter().try_for_each(&mut check)?;
+            if let Some(handler) = &try_stmt.handler {
+                handler.body.statements.iter().try_for_each(&mut check)?;
+            }
+
+            if let Some(finalizer) = &try_stmt.finalizer {
+                finalizer.statements.iter().try_for_each(check)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// The labels directly applied to an iteration statement are valid `continue` targets in its body.
+fn early_errors_iteration_body<'a>(
+    body: &'a Stmt,
+    label_set: &mut LabelSet<'a>,
+    current_labels: usize,
+) -> Result<()> {
+    let first_current = label_set.labels.len() - current_labels;
+    for label in &mut label_set.labels[first_current..] {
+        label.is_iteration = true;
+    }
+
+    let result = grow_stack(|| early_errors_undefined_targets(body, label_set, 0));
+
+    for label in &mut label_set.labels[first_current..] {
+        label.is_iteration = false;
+    }
+    result
+}
+
+fn undefined_label(label: &Ident) -> Error {
+    Error::syntax_error(
+        format!("Undefined label '{}'", label.name),
+        label.span.clone(),
+    )
+}
+
 /// Assumes the `list` is sorted.
 fn get_first_duplicate<'a>(list: &[&'a str]) -> Option<&'a str> {
     let mut iter = list.iter().peekable();
@@ -1,17 +1,44 @@
-use crate::error::Result;
+use crate::error::{ErrorKind, Result};
+use crate::warning::WarningKind;
 use crate::{Error, Parser, ThenTry};
 use fajt_ast::{
-    CatchClause, SourceType, Stmt, StmtBlock, StmtBreak, StmtContinue, StmtDebugger, StmtEmpty,
-    StmtExpr, StmtIf, StmtLabeled, StmtReturn, StmtSwitch, StmtThrow, StmtTry, StmtWith,
+    CatchClause, SourceType, Spanned, Stmt, StmtBlock, StmtBreak, StmtContinue, StmtDebugger,
+    StmtEmpty, StmtExpr, StmtIf, StmtLabeled, StmtReturn, StmtSwitch, StmtThrow, StmtTry, StmtWith,
     SwitchCase, VariableKind,
 };
 use fajt_common::io::{PeekRead, ReReadWithState};
 
 use fajt_lexer::punct;
-use fajt_lexer::token::Token;
+use fajt_lexer::token::{Token, TokenValue};
 use fajt_lexer::token_matches;
 use fajt_lexer::{keyword, LexerState};
 
+/// Keywords and punctuators that start a statement or declaration, other than expressions.
+const STATEMENT_START: &[TokenValue] = &[
+    punct!(";"),
+    punct!("{"),
+    keyword!("var"),
+    keyword!("let"),
+    keyword!("const"),
+    keyword!("function"),
+    keyword!("async"),
+    keyword!("class"),
+    keyword!("if"),
+    keyword!("for"),
+    keyword!("while"),
+    keyword!("do"),
+    keyword!("switch"),
+    keyword!("break"),
+    keyword!("continue"),
+    keyword!("return"),
+    keyword!("throw"),
+    keyword!("try"),
+    keyword!("with"),
+    keyword!("debugger"),
+    keyword!("import"),
+    keyword!("export"),
+];
+
 impl<I> Parser<'_, I>
 where
     I: PeekRead<Token, Error = fajt_lexer::error::Error>,
@@ -36,10 +63,15 @@ where
             return Ok(decl);
         }
 
-        self.parse_stmt()
+        self.parse_nested_stmt()
     }
 
+    /// Parses a statement nested in another statement, e.g. the body of a loop.
     pub(super) fn parse_stmt(&mut self) -> Result<Stmt> {
+        self.nested(Self::parse_nested_stmt)
+    }
+
+    fn parse_nested_stmt(&mut self) -> Result<Stmt> {
         Ok(match self.current()? {
             token_matches!(punct!(";")) => self.parse_empty_stmt()?,
             token_matches!(punct!("{")) => self.parse_block_stmt()?,
@@ -56,42 +88,64 @@ where
             token_matches!(keyword!("while")) => self.parse_while_stmt()?,
             token_matches!(keyword!("for")) => self.parse_for_stmt()?,
             token_matches!(keyword!("switch")) => self.parse_switch_stmt()?,
+            token_matches!(keyword!("const") | keyword!("class")) => {
+                self.reject_declaration_in_single_statement_context()?
+            }
+            token_matches!(keyword!("let")) if self.is_lexical_declaration_in_stmt() => {
+                self.reject_declaration_in_single_statement_context()?
+            }
             _ if self.is_identifier() && self.peek_matches(&punct!(":")) => {
                 self.parse_labeled_stmt()?
             }
-            _ if self.is_expr_stmt()? => self
-                .with_context(self.context.with_in(true))
-                .parse_expr_stmt()?,
+            _ if self.is_expr_stmt()? => {
+                let token = self.current()?.clone();
+                self.with_context(self.context.with_in(true))
+                    .parse_expr_stmt()
+                    .map_err(|error| Self::expected_stmt_error(error, token))?
+            }
             token => return Err(Error::unexpected_token(token.clone())),
         })
     }
 
+    /// An unexpected first token of an expression statement means that no statement matched, the
+    /// error is replaced with one listing what can start a statement.
+    fn expected_stmt_error(error: Error, token: Token) -> Error {
+        if matches!(error.kind(), ErrorKind::UnexpectedToken(_, None))
+            && *error.span() == token.span
+        {
+            Error::expected_one_of(token, "statement", STATEMENT_START)
+        } else {
+            error
+        }
+    }
+
     pub(super) fn parse_declaration(&mut self) -> Result<Option<Stmt>> {
         Ok(match self.current()? {
             token_matches!(keyword!("function")) => Some(self.parse_function_declaration()?),
-            token_matches!(keyword!("async")) if self.peek_matches(&keyword!("function")) => {
+            token_matches!(keyword!("async"))
+                if self.peek_matches(&keyword!("function")) && !self.followed_by_new_line() =>
+            {
                 Some(self.parse_async_function_declaration()?)
             }
             token_matches!(keyword!("class")) => Some(
                 self.with_context(self.context.with_strict(true))
                     .parse_class_decl()?,
             ),
+            #[cfg(feature = "decorators")]
+            token_matches!(punct!("@")) => Some(
+                self.with_context(self.context.with_strict(true))
+                    .parse_class_decl()?,
+            ),
             token_matches!(keyword!("import")) => {
                 if self.source_type == SourceType::Script {
-                    return Err(Error::syntax_error(
-                        "Cannot use `import` statement outside a module".to_owned(),
-                        self.current()?.span.clone(),
-                    ));
+                    return Err(Error::module_declaration_in_script(self.current()?.clone()));
                 }
 
                 Some(self.parse_import_declaration()?)
             }
             token_matches!(keyword!("export")) => {
                 if self.source_type == SourceType::Script {
-                    return Err(Error::syntax_error(
-                        "Cannot use `export` statement outside a module".to_owned(),
-                        self.current()?.span.clone(),
-                    ));
+                    return Err(Error::module_declaration_in_script(self.current()?.clone()));
                 }
 
                 Some(self.parse_export_declaration()?)
@@ -106,6 +160,23 @@ where
         })
     }
 
+    /// Returns `true` if `let` at current position starts a lexical declaration where only a
+    /// statement is allowed, i.e. `if (a) let b = c;`. `let` followed by a new line is an
+    /// identifier reference, unless followed by `[`.
+    fn is_lexical_declaration_in_stmt(&self) -> bool {
+        self.peek_matches(&punct!("["))
+            || (!self.followed_by_new_line() && self.peek_matches_lexical_binding())
+    }
+
+    /// Error for declarations that are the body of statements like `if` and `while`, these must be
+    /// wrapped in a block.
+    fn reject_declaration_in_single_statement_context(&self) -> Result<Stmt> {
+        Err(Error::syntax_error(
+            "Lexical declaration cannot appear in a single-statement context".to_owned(),
+            self.current()?.span.clone(),
+        ))
+    }
+
     /// Check if current position matches the start of an expression statement as specified in the
     /// `ExpressionStatement` production.
     fn is_expr_stmt(&self) -> Result<bool> {
@@ -129,6 +200,10 @@ where
 
     /// Parses the `BlockStatement` production.
     fn parse_block_stmt(&mut self) -> Result<Stmt> {
+        self.nested(Self::parse_nested_block_stmt)
+    }
+
+    fn parse_nested_block_stmt(&mut self) -> Result<Stmt> {
         let span_start = self.position();
         self.consume_assert(&punct!("{"))?;
 
@@ -146,6 +221,15 @@ where
         Ok(StmtBlock { span, statements }.into())
     }
 
+    /// Parses the `Block` production where a `StmtBlock` is needed, i.e. in `try` statements and
+    /// `do` expressions.
+    pub(super) fn parse_block(&mut self) -> Result<StmtBlock> {
+        let stmt = self.parse_block_stmt()?;
+        let span = stmt.span().clone();
+        stmt.try_as_block_stmt()
+            .ok_or_else(|| Error::syntax_error("Expected block statement".to_owned(), span))
+    }
+
     /// Parses the `ExpressionStatement` production.
     fn parse_expr_stmt(&mut self) -> Result<Stmt> {
         let span_start = self.position();
@@ -191,6 +275,13 @@ where
         self.maybe_consume(&punct!(";"))?;
 
         let span = self.span_from(span_start);
+        if label.is_none() && !self.context.in_iteration && !self.context.in_switch {
+            return Err(Error::syntax_error(
+                "Illegal break statement".to_owned(),
+                span,
+            ));
+        }
+
         Ok(StmtBreak { span, label }.into())
     }
 
@@ -203,6 +294,13 @@ where
         self.maybe_consume(&punct!(";"))?;
 
         let span = self.span_from(span_start);
+        if !self.context.in_iteration {
+            return Err(Error::syntax_error(
+                "Illegal continue statement: no surrounding iteration statement".to_owned(),
+                span,
+            ));
+        }
+
         Ok(StmtContinue { span, label }.into())
     }
 
@@ -271,34 +369,64 @@ where
     }
 
     /// Parses the `IfStatement` production.
+    ///
+    /// Chains of `else if` are parsed in a loop rather than recursively, since they may be long.
     fn parse_if_stmt(&mut self) -> Result<Stmt> {
-        let span_start = self.position();
+        let mut branches = Vec::new();
+        let alternate = loop {
+            self.check_depth(branches.len())?;
+
+            let span_start = self.position();
+            self.consume_assert(&keyword!("if"))?;
+            self.consume_assert(&punct!("("))?;
+            let condition = self.with_context(self.context.with_in(true)).parse_expr()?;
+            self.consume_assert(&punct!(")"))?;
 
-        self.consume_assert(&keyword!("if"))?;
-        self.consume_assert(&punct!("("))?;
-        let condition = self.with_context(self.context.with_in(true)).parse_expr()?;
-        self.consume_assert(&punct!(")"))?;
+            let consequent = self.parse_stmt()?;
+            branches.push((span_start, condition, consequent));
 
-        let consequent = self.parse_stmt()?;
-        let alternate = self
-            .maybe_consume(&keyword!("else"))?
-            .then_try(|| self.parse_stmt())?;
+            if !self.maybe_consume(&keyword!("else"))? {
+                break None;
+            }
 
-        let span = self.span_from(span_start);
-        Ok(StmtIf {
-            span,
-            condition: Box::new(condition),
-            consequent: Box::new(consequent),
-            alternate: alternate.map(Box::new),
+            if !self.current_matches(&keyword!("if")) {
+                break Some(self.parse_stmt()?);
+            }
+        };
+
+        // All statements in the chain end where the last one ends.
+        let mut stmt = alternate;
+        for (span_start, condition, consequent) in branches.into_iter().rev() {
+            let span = self.span_from(span_start);
+            stmt = Some(
+                StmtIf {
+                    span,
+                    condition: Box::new(condition),
+                    consequent: Box::new(consequent),
+                    alternate: stmt.map(Box::new),
+                }
+                .into(),
+            );
         }
-        .into())
+
+        // There is always at least one branch.
+        Ok(stmt.unwrap())
     }
 
     /// Parses the `WithStatement` production.
     fn parse_with_stmt(&mut self) -> Result<Stmt> {
         let span_start = self.position();
 
-        self.consume_assert(&keyword!("with"))?;
+        let with = self.consume_assert(&keyword!("with"))?;
+        if self.context.is_strict {
+            return Err(Error::syntax_error(
+                "Strict mode code may not include a with statement".to_owned(),
+                with.span,
+            ));
+        }
+
+        self.warn(WarningKind::WithStatement, with.span);
+
         self.consume_assert(&punct!("("))?;
         let object = self.parse_expr()?;
         self.consume_assert(&punct!(")"))?;
@@ -319,7 +447,7 @@ where
         let span_start = self.position();
 
         self.consume_assert(&keyword!("try"))?;
-        let block = self.parse_block_stmt()?.unwrap_block_stmt();
+        let block = self.parse_block()?;
 
         let handler = self
             .current_matches(&keyword!("catch"))
@@ -327,7 +455,7 @@ where
 
         let finalizer = self
             .maybe_consume(&keyword!("finally"))?
-            .then_try(|| Ok(self.parse_block_stmt()?.unwrap_block_stmt()))?;
+            .then_try(|| self.parse_block())?;
 
         let span = self.span_from(span_start);
         if handler.is_none() && finalizer.is_none() {
@@ -357,7 +485,7 @@ where
             Ok(pattern)
         })?;
 
-        let body = self.parse_block_stmt()?.unwrap_block_stmt();
+        let body = self.parse_block()?;
 
         let span = self.span_from(span_start);
         Ok(CatchClause {
@@ -377,7 +505,9 @@ where
         let discriminant = self.with_context(self.context.with_in(true)).parse_expr()?;
         self.consume_assert(&punct!(")"))?;
 
-        let cases = self.parse_case_block()?;
+        let cases = self
+            .with_context(self.context.with_in_switch(true))
+            .parse_case_block()?;
 
         let span = self.span_from(span_start);
         Ok(StmtSwitch {
@@ -390,6 +520,10 @@ where
 
     /// Parses the `CaseBlock` production.
     fn parse_case_block(&mut self) -> Result<Vec<SwitchCase>> {
+        self.nested(Self::parse_nested_case_block)
+    }
+
+    fn parse_nested_case_block(&mut self) -> Result<Vec<SwitchCase>> {
         self.consume_assert(&punct!("{"))?;
 
         let mut cases = Vec::new();
@@ -1,4 +1,5 @@
 use crate::error::{Error, Result};
+use crate::static_semantics::BindingPatternSemantics;
 use crate::{Parser, ThenTry};
 use fajt_ast::{BindingPattern, Stmt, StmtVariable, VariableDeclaration, VariableKind};
 use fajt_common::io::{PeekRead, ReReadWithState};
@@ -17,9 +18,9 @@ where
 
         let declarations = if kind == VariableKind::Var {
             self.with_context(self.context.with_in(true))
-                .parse_variable_declarations()?
+                .parse_variable_declarations(&kind)?
         } else {
-            self.parse_variable_declarations()?
+            self.parse_variable_declarations(&kind)?
         };
         self.maybe_consume(&punct!(";"))?;
 
@@ -32,12 +33,15 @@ where
         .into())
     }
 
-    pub(super) fn parse_variable_declarations(&mut self) -> Result<Vec<VariableDeclaration>> {
-        let mut declarations = vec![self.parse_variable_declaration()?];
+    pub(super) fn parse_variable_declarations(
+        &mut self,
+        kind: &VariableKind,
+    ) -> Result<Vec<VariableDeclaration>> {
+        let mut declarations = vec![self.parse_variable_declaration(kind)?];
 
         loop {
             if self.maybe_consume(&punct!(","))? {
-                declarations.push(self.parse_variable_declaration()?);
+                declarations.push(self.parse_variable_declaration(kind)?);
             } else {
                 break;
             }
@@ -53,9 +57,12 @@ where
     }
 
     /// Parses the `VariableDeclaration` or `LexicalBinding` production.
-    fn parse_variable_declaration(&mut self) -> Result<VariableDeclaration> {
+    fn parse_variable_declaration(&mut self, kind: &VariableKind) -> Result<VariableDeclaration> {
         let span_start = self.position();
         let pattern = self.parse_binding_pattern()?;
+        if *kind != VariableKind::Var {
+            pattern.early_errors_lexical_binding()?;
+        }
 
         if !matches!(pattern, BindingPattern::Ident(_)) && !self.current_matches(&punct!("=")) {
             let span = self.span_from(span_start);
@@ -0,0 +1,65 @@
+use fajt_ast::Span;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Construct that is valid but worth pointing out, for example to a linter. Unlike errors,
+/// warnings do not stop parsing, see `parse_with_warnings`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Warning {
+    kind: WarningKind,
+    span: Span,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub enum WarningKind {
+    /// `with` statement, only allowed in sloppy mode.
+    WithStatement,
+    /// Number literal with a leading zero, e.g. `010`, only allowed in sloppy mode.
+    LegacyOctalLiteral,
+    /// String literal with an octal escape sequence, e.g. `"\01"`, only allowed in sloppy mode.
+    LegacyOctalEscape,
+}
+
+impl Warning {
+    pub(crate) fn new(kind: WarningKind, span: Span) -> Self {
+        Warning { kind, span }
+    }
+
+    pub fn kind(&self) -> &WarningKind {
+        &self.kind
+    }
+
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    pub fn message(&self) -> &'static str {
+        match self.kind {
+            WarningKind::WithStatement => "Use of `with` statement",
+            WarningKind::LegacyOctalLiteral => "Use of legacy octal literal",
+            WarningKind::LegacyOctalEscape => "Use of legacy octal escape sequence",
+        }
+    }
+}
+
+/// Warnings in the order they were found. Tokens may be re-read after a rewind, so the same
+/// warning can be found more than once, it is only kept the first time.
+#[derive(Debug, Default)]
+pub(crate) struct Warnings {
+    warnings: Vec<Warning>,
+    seen: HashSet<(WarningKind, usize, usize)>,
+}
+
+impl Warnings {
+    pub(crate) fn add(&mut self, warning: Warning) {
+        let key = (warning.kind.clone(), warning.span.start, warning.span.end);
+        if self.seen.insert(key) {
+            self.warnings.push(warning);
+        }
+    }
+
+    pub(crate) fn take(&mut self) -> Vec<Warning> {
+        self.seen.clear();
+        std::mem::take(&mut self.warnings)
+    }
+}
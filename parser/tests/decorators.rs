@@ -0,0 +1,23 @@
+#[cfg(feature = "decorators")]
+use fajt_ast::{ClassElement, Stmt};
+use fajt_parser::parse_statement;
+
+#[cfg(feature = "decorators")]
+#[test]
+fn decorated_class_and_method() {
+    let stmt = parse_statement("@a @b(c) class A { @d m() {} }").unwrap();
+    let Stmt::ClassDecl(class) = stmt else {
+        panic!("Expected class declaration, got {stmt:?}");
+    };
+    assert_eq!(class.decorators.len(), 2);
+
+    let ClassElement::Method(method) = &class.body[0];
+    assert_eq!(method.decorators.len(), 1);
+}
+
+#[cfg(not(feature = "decorators"))]
+#[test]
+fn decorators_without_feature() {
+    assert!(parse_statement("@a class A {}").is_err());
+    assert!(parse_statement("class A { @a m() {} }").is_err());
+}
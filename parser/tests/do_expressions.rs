@@ -0,0 +1,20 @@
+#[cfg(feature = "do-expressions")]
+use fajt_ast::Expr;
+use fajt_parser::parse_expression;
+
+#[cfg(feature = "do-expressions")]
+#[test]
+fn do_expression() {
+    let expr = parse_expression("do { a; b }").unwrap();
+    let Expr::Do(expr) = expr else {
+        panic!("Expected do expression, got {expr:?}");
+    };
+    assert_eq!(expr.body.statements.len(), 2);
+}
+
+#[cfg(not(feature = "do-expressions"))]
+#[test]
+fn do_expression_without_feature() {
+    assert!(parse_expression("do { a; b }").is_err());
+    assert!(parse_expression("(do {})").is_err());
+}
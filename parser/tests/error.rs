@@ -0,0 +1,64 @@
+use fajt_ast::Span;
+use fajt_parser::error::{Error, ErrorKind};
+use fajt_parser::parse_program;
+
+#[test]
+fn span_and_message_of_syntax_error() {
+    let error = parse_program("var a = 1;\na = ;").unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedToken(..)));
+    assert_eq!(error.span(), &Span::new(15, 16));
+    assert_eq!(error.message(), "Unexpected token `;`");
+    assert_eq!(error.to_string(), "Syntax error: Unexpected token `;`");
+}
+
+#[test]
+fn span_and_message_of_custom_syntax_error() {
+    let error = parse_program("a ?? b || c").unwrap_err();
+    assert_eq!(error.span(), &Span::new(2, 4));
+    assert_eq!(
+        error.message(),
+        "Cannot mix `??` with `&&` or `||` without parentheses"
+    );
+}
+
+#[test]
+fn message_of_end_of_input() {
+    let error = parse_program("a +").unwrap_err();
+    assert_eq!(error.message(), "Unexpected end of input");
+    assert_eq!(error.to_string(), "Syntax error: Unexpected end of input");
+}
+
+#[test]
+fn serde_round_trip() {
+    for source in [
+        "a ?? b || c",
+        "a = ;",
+        "a;\n)",
+        "class A { constructor() { super(); } }",
+        "var 1;",
+        "a => {} + 1",
+    ] {
+        let error = parse_program(source).unwrap_err();
+
+        let json = serde_json::to_value(&error).unwrap();
+        assert_ne!(json["span"], serde_json::json!("0:0"), "{source}");
+        assert_eq!(json["span"], serde_json::json!(error.span()), "{source}");
+
+        let deserialized: Error = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, error, "{source}");
+    }
+}
+
+#[test]
+fn serialized_syntax_error() {
+    let error = parse_program("a ?? b || c").unwrap_err();
+    assert_eq!(
+        serde_json::to_value(&error).unwrap(),
+        serde_json::json!({
+            "kind": {
+                "SyntaxError": "Cannot mix `??` with `&&` or `||` without parentheses"
+            },
+            "span": "2:4"
+        })
+    );
+}
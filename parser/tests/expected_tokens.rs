@@ -0,0 +1,28 @@
+use fajt_lexer::token::TokenValue;
+use fajt_lexer::{keyword, punct};
+use fajt_parser::error::ErrorKind;
+use fajt_parser::parse_script;
+
+#[test]
+fn stray_token_at_statement_position() {
+    let error = parse_script("a;\n)").unwrap_err();
+    match error.kind() {
+        ErrorKind::ExpectedOneOf(found, production, first_tokens) => {
+            assert_eq!(found, &punct!(")"));
+            assert_eq!(*production, "statement");
+            assert!(first_tokens.contains(&keyword!("if")));
+            assert!(first_tokens.contains(&punct!("{")));
+            assert!(!first_tokens.contains(&punct!(")")));
+        }
+        kind => panic!("Unexpected error kind {:?}", kind),
+    }
+}
+
+#[test]
+fn error_inside_expression_statement() {
+    let error = parse_script("a + );").unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::UnexpectedToken(TokenValue::Punctuator(_), None)
+    ));
+}
@@ -0,0 +1,102 @@
+use fajt_ast::structural::eq_ignoring_spans;
+use fajt_ast::{Span, Spanned};
+use fajt_parser::{parse_expression, parse_json};
+
+fn assert_json_error(source: &str, message: &str) {
+    let error = parse_json(source).unwrap_err();
+    assert_eq!(error.message(), message, "Source: {source}");
+}
+
+#[test]
+fn json_values_parse_same_as_expressions() {
+    let sources = [
+        "null",
+        "true",
+        "false",
+        "0",
+        "-1.5e+10",
+        r#""a\"\\\/\b\f\n\r\té""#,
+        "[]",
+        "{}",
+        r#"{"a": [1, -2, {"b": null}], "c": "d"}"#,
+        " \t\r\n[ 1 , 2 ] \n",
+    ];
+
+    for source in sources {
+        let expected = parse_expression(source).unwrap();
+        assert_eq!(parse_json(source).unwrap(), expected, "Source: {source}");
+    }
+}
+
+#[test]
+fn unquoted_property_name() {
+    assert_json_error(
+        "{a: 1}",
+        "Property names must be double quoted strings in JSON",
+    );
+    assert_json_error("{'a': 1}", "Invalid string in JSON");
+    assert_json_error(
+        "{1: 1}",
+        "Property names must be double quoted strings in JSON",
+    );
+}
+
+#[test]
+fn trailing_commas() {
+    assert_json_error("[1, 2,]", "Trailing commas are not allowed in JSON");
+    assert_json_error(r#"{"a": 1,}"#, "Trailing commas are not allowed in JSON");
+    assert!(parse_json("[1,,2]").is_err());
+    assert!(parse_json("[,]").is_err());
+}
+
+#[test]
+fn comments() {
+    assert_json_error("// a\n1", "Comments are not allowed in JSON");
+    assert_json_error("[1 /* a */]", "Comments are not allowed in JSON");
+    assert_json_error("1 // a", "Comments are not allowed in JSON");
+}
+
+#[test]
+fn javascript_only_literals() {
+    assert_json_error("'a'", "Invalid string in JSON");
+    assert_json_error(r#""\x41""#, "Invalid string in JSON");
+    assert_json_error(r#""\u{41}""#, "Invalid string in JSON");
+    assert_json_error(r#""\0""#, "Invalid string in JSON");
+    assert_json_error("\"a\\\nb\"", "Invalid string in JSON");
+    assert_json_error("\"\t\"", "Invalid string in JSON");
+
+    assert_json_error("0x1", "Invalid number in JSON");
+    assert_json_error("01", "Invalid number in JSON");
+    assert_json_error(".5", "Invalid number in JSON");
+    assert_json_error("5.", "Invalid number in JSON");
+    assert_json_error("1_000", "Invalid number in JSON");
+
+    assert!(parse_json("1n").is_err());
+    assert!(parse_json("+1").is_err());
+    assert!(parse_json("- 1").is_err());
+    assert!(parse_json("-\"a\"").is_err());
+    assert!(parse_json("undefined").is_err());
+    assert!(parse_json("NaN").is_err());
+    assert!(parse_json("`a`").is_err());
+}
+
+#[test]
+fn javascript_only_whitespace() {
+    assert_json_error("\u{a0}1", "Invalid whitespace in JSON");
+    assert_json_error("[1,\u{b}2]", "Invalid whitespace in JSON");
+}
+
+#[test]
+fn single_value() {
+    assert!(parse_json("").is_err());
+    assert!(parse_json("1 2").is_err());
+    assert!(parse_json("[1] [2]").is_err());
+}
+
+#[test]
+fn leading_byte_order_mark() {
+    let expected = parse_json(r#"{"a": 1}"#).unwrap();
+    let expr = parse_json("\u{feff}{\"a\": 1}").unwrap();
+    assert!(eq_ignoring_spans(&expr, &expected));
+    assert_eq!(expr.span(), &Span::new(3, 11));
+}
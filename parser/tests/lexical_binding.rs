@@ -0,0 +1,73 @@
+use fajt_ast::{BindingPattern, Expr, ForDeclaration, Stmt, VariableKind};
+use fajt_parser::parse_statement;
+
+/// Returns the binding pattern of `source` if it is a single `let` declaration.
+fn let_binding(source: &str) -> Option<BindingPattern> {
+    match parse_statement(source).unwrap() {
+        Stmt::Variable(variable) if variable.kind == VariableKind::Let => {
+            Some(variable.declarations.into_iter().next().unwrap().pattern)
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn let_followed_by_identifier() {
+    assert!(matches!(
+        let_binding("let x"),
+        Some(BindingPattern::Ident(_))
+    ));
+}
+
+#[test]
+fn let_followed_by_array_pattern() {
+    assert!(matches!(
+        let_binding("let [a] = b"),
+        Some(BindingPattern::Array(_))
+    ));
+}
+
+#[test]
+fn let_followed_by_object_pattern() {
+    assert!(matches!(
+        let_binding("let {a} = b"),
+        Some(BindingPattern::Object(_))
+    ));
+}
+
+#[test]
+fn let_followed_by_contextual_keyword() {
+    for keyword in [
+        "async", "of", "get", "set", "static", "as", "from", "target", "await", "yield",
+    ] {
+        let source = format!("let {keyword}");
+        match let_binding(&source) {
+            Some(BindingPattern::Ident(ident)) => assert_eq!(ident.name, keyword),
+            binding => panic!("Expected `{source}` to bind `{keyword}`, got {binding:?}"),
+        }
+    }
+}
+
+#[test]
+fn let_followed_by_reserved_word_is_identifier() {
+    for source in ["let instanceof x", "let in x"] {
+        let stmt = parse_statement(source).unwrap();
+        let Stmt::Expr(stmt) = stmt else {
+            panic!("Expected `{source}` to be an expression statement");
+        };
+
+        let Expr::Binary(binary) = *stmt.expr else {
+            panic!("Expected `{source}` to be a binary expression");
+        };
+        assert!(matches!(*binary.left, Expr::IdentRef(ident) if ident.name == "let"));
+    }
+}
+
+#[test]
+fn let_in_for_of_head() {
+    let Stmt::ForOf(for_of) = parse_statement("for (let of of a);").unwrap() else {
+        panic!("Expected for-of statement");
+    };
+
+    assert!(matches!(for_of.left, ForDeclaration::Declaration(_)));
+}
@@ -0,0 +1,102 @@
+use fajt_ast::Span;
+use fajt_parser::error::emitter::ErrorEmitter;
+use fajt_parser::error::line_index::LineIndex;
+use fajt_parser::parse_script;
+
+#[test]
+fn line_col_single_line() {
+    let index = LineIndex::new("a = b;");
+    assert_eq!(index.line_count(), 1);
+    assert_eq!(index.line_col(0), (1, 1));
+    assert_eq!(index.line_col(4), (1, 5));
+    assert_eq!(index.line_col(6), (1, 7));
+}
+
+#[test]
+fn line_col_multiple_lines() {
+    let index = LineIndex::new("a\nb\r\nc\rd\u{2028}e\n");
+    assert_eq!(index.line_count(), 6);
+    assert_eq!(index.line_col(0), (1, 1));
+    assert_eq!(index.line_col(1), (1, 2));
+    assert_eq!(index.line_col(2), (2, 1));
+    assert_eq!(index.line_col(5), (3, 1));
+    assert_eq!(index.line_col(7), (4, 1));
+    assert_eq!(index.line_col(11), (5, 1));
+    assert_eq!(index.line_col(13), (6, 1));
+}
+
+#[test]
+fn columns_count_code_points() {
+    // `é` is 2 bytes, `日本` is 6 bytes and `😀` is 4 bytes.
+    let source = "é = 1;\nconst 日本 = '😀' + x;";
+    let index = LineIndex::new(source);
+
+    assert_eq!(index.line_col(source.find('=').unwrap()), (1, 3));
+    assert_eq!(index.line_col(source.find('本').unwrap()), (2, 8));
+    assert_eq!(index.line_col(source.find('+').unwrap()), (2, 16));
+    assert_eq!(index.line_col(source.find('x').unwrap()), (2, 18));
+}
+
+#[test]
+fn offset_is_inverse_of_line_col() {
+    let source = "é = 1;\r\nconst 日本 = '😀' + x;\n";
+    let index = LineIndex::new(source);
+
+    for (offset, _) in source.char_indices() {
+        let (line, column) = index.line_col(offset);
+        assert_eq!(index.offset(line, column), Some(offset));
+    }
+}
+
+#[test]
+fn offset_outside_of_source() {
+    let index = LineIndex::new("ab\ncd");
+    assert_eq!(index.offset(1, 3), Some(2));
+    assert_eq!(index.offset(1, 5), None);
+    assert_eq!(index.offset(2, 3), Some(5));
+    assert_eq!(index.offset(3, 1), None);
+    assert_eq!(index.offset(0, 1), None);
+    assert_eq!(index.offset(1, 0), None);
+}
+
+#[test]
+fn line_span() {
+    let index = LineIndex::new("ab\r\ncd\n");
+    assert_eq!(index.line_span(1), Some(Span::new(0, 2)));
+    assert_eq!(index.line_span(2), Some(Span::new(4, 6)));
+    assert_eq!(index.line_span(3), Some(Span::new(7, 7)));
+    assert_eq!(index.line_span(4), None);
+}
+
+#[test]
+fn emitted_column_counts_code_points() {
+    let source = "var 日本 = ;";
+    let error = parse_script(source).unwrap_err();
+
+    let mut output = Vec::new();
+    let mut emitter = ErrorEmitter::new("test.js", source, &mut output);
+    emitter.emit_error(&error).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains(" --> test.js:1:10\n"), "{output}");
+}
+
+#[test]
+fn emitted_error_after_byte_order_mark() {
+    let source = "\u{feff}var a = ;";
+    let error = parse_script(source).unwrap_err();
+    assert_eq!(error.span(), &Span::new(11, 12));
+    assert_eq!(error.span().slice(source), ";");
+
+    let index = LineIndex::new(source);
+    assert_eq!(index.line_col(3), (1, 1));
+    assert_eq!(index.offset(1, 1), Some(3));
+
+    let mut output = Vec::new();
+    let mut emitter = ErrorEmitter::new("test.js", source, &mut output);
+    emitter.emit_error(&error).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains(" --> test.js:1:9\n"), "{output}");
+    assert!(output.contains("1 | var a = ;\n"), "{output}");
+}
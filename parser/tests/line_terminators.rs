@@ -0,0 +1,64 @@
+use fajt_ast::{Program, Stmt};
+use fajt_parser::error::emitter::ErrorEmitter;
+use fajt_parser::parse_script;
+
+fn error_message(source: &str) -> String {
+    let error = parse_script(source).unwrap_err();
+
+    let mut output = Vec::new();
+    let mut emitter = ErrorEmitter::new("test.js", source, &mut output);
+    emitter.emit_error(&error).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+fn body(source: &str) -> Vec<Stmt> {
+    match parse_script(source).unwrap() {
+        Program::Script(stmt_list) | Program::Module(stmt_list) => stmt_list.body,
+    }
+}
+
+fn statement_count(source: &str) -> usize {
+    body(source).len()
+}
+
+#[test]
+fn automatic_semicolon_insertion() {
+    assert_eq!(statement_count("a\nb"), 2);
+    assert_eq!(statement_count("a\r\nb"), 2);
+    assert_eq!(statement_count("a\rb"), 2);
+    assert_eq!(statement_count("a\u{2028}b"), 2);
+}
+
+#[test]
+fn error_line_with_crlf() {
+    assert_eq!(
+        error_message("a;\r\nb;\r\n)"),
+        "Syntax error: Unexpected token `)`\n --> test.js:3:1\n  |\n3 | )\n  | ^ Unexpected token, found `)`, expected statement\n"
+    );
+}
+
+#[test]
+fn error_line_with_carriage_return() {
+    assert_eq!(
+        error_message("a;\rb;\r)"),
+        "Syntax error: Unexpected token `)`\n --> test.js:3:1\n  |\n3 | )\n  | ^ Unexpected token, found `)`, expected statement\n"
+    );
+}
+
+#[test]
+fn error_line_with_mixed_line_terminators() {
+    assert_eq!(
+        error_message("a;\r\nb;\nc;\rd )\r\ne;"),
+        "Syntax error: Unexpected token `)`\n --> test.js:4:3\n  |\n4 | d )\n  |   ^ Unexpected token\n"
+    );
+}
+
+#[test]
+fn return_statement_ends_at_carriage_return() {
+    let body = body("function f() {\n    return\r    a\n}");
+    let Stmt::FunctionDecl(function) = &body[0] else {
+        panic!("Expected function declaration");
+    };
+
+    assert_eq!(function.body.statements.len(), 2);
+}
@@ -0,0 +1,109 @@
+use fajt_ast::{Program, SourceType};
+use fajt_common::io::PeekReader;
+use fajt_lexer::Lexer;
+use fajt_parser::error::ErrorKind;
+use fajt_parser::{parse_script, Parse, Parser};
+
+/// Parses on the current thread, i.e. with the default stack size of test threads, returns the
+/// kind of error if parsing failed.
+fn parse_error(source: &str) -> Option<ErrorKind> {
+    parse_script(source).err().map(|error| error.kind().clone())
+}
+
+fn nested(open: &str, inner: &str, close: &str, depth: usize) -> String {
+    format!("{}{}{}", open.repeat(depth), inner, close.repeat(depth))
+}
+
+fn assert_nesting_too_deep(source: String) {
+    assert_eq!(parse_error(&source), Some(ErrorKind::NestingTooDeep));
+}
+
+#[test]
+fn deeply_nested_parens() {
+    assert_nesting_too_deep(nested("(", "a", ")", 100_000));
+}
+
+#[test]
+fn deeply_nested_arrays_and_objects() {
+    assert_nesting_too_deep(nested("[", "a", "]", 100_000));
+    assert_nesting_too_deep(nested("({ a: ", "a", " })", 100_000));
+}
+
+#[test]
+fn deeply_nested_statements() {
+    assert_nesting_too_deep(nested("{", "", "}", 100_000));
+    assert_nesting_too_deep(nested("if (a) ", ";", "", 100_000));
+    assert_nesting_too_deep(format!("{};", "if (a) ; else ".repeat(100_000)));
+}
+
+#[test]
+fn deeply_nested_operators() {
+    assert_nesting_too_deep(nested("!", "a", "", 100_000));
+    assert_nesting_too_deep(nested("a ** ", "a", "", 100_000));
+    assert_nesting_too_deep(nested("a => ", "a", "", 100_000));
+    assert_nesting_too_deep(nested("new ", "a", "", 100_000));
+}
+
+#[test]
+fn deeply_nested_binding_patterns() {
+    assert_nesting_too_deep(format!("let {}", nested("[", "a", "]", 100_000)));
+}
+
+#[test]
+fn deeply_nested_functions_and_classes() {
+    assert_nesting_too_deep(nested("function f() {", "", "}", 100_000));
+    assert_nesting_too_deep(nested("(function () {", "", "})", 100_000));
+    assert_nesting_too_deep(nested("() => {", "", "}", 100_000));
+    assert_nesting_too_deep(nested("class A { m() {", "", "} }", 100_000));
+    assert_nesting_too_deep(nested("a(function () { return ", "a", "})", 100_000));
+}
+
+#[test]
+fn realistic_nesting() {
+    assert_eq!(parse_error(&nested("(", "a", ")", 200)), None);
+    assert_eq!(parse_error(&nested("[", "a", "]", 200)), None);
+    assert_eq!(parse_error(&nested("if (a) {", "", "}", 200)), None);
+    assert_eq!(parse_error(&nested("if (a) ", ";", "", 200)), None);
+    assert_eq!(parse_error(&nested("a = ", "a", "", 200)), None);
+    assert_eq!(parse_error(&nested("!", "a", "", 200)), None);
+}
+
+#[test]
+fn nested_callbacks() {
+    let source = nested("a(function () {\n    return ", "a", ";\n});", 100);
+    assert_eq!(parse_error(&source), None);
+
+    let source = nested("a(() => {\n    if (b) {\n        ", "", "}\n});", 100);
+    assert_eq!(parse_error(&source), None);
+}
+
+#[test]
+fn long_else_if_chain() {
+    let source = format!("{}{{}}", "if (a) {} else ".repeat(500));
+    assert_eq!(parse_error(&source), None);
+}
+
+#[test]
+fn nesting_below_max_depth() {
+    assert_eq!(parse_error(&nested("(", "a", ")", 10)), None);
+    assert_eq!(parse_error(&nested("[", "a", "]", 10)), None);
+    assert_eq!(parse_error(&nested("{", "", "}", 10)), None);
+    assert_eq!(parse_error(&nested("function f() {", "", "}", 10)), None);
+}
+
+#[test]
+fn configurable_max_depth() {
+    let parse_error = |source: &str, max_depth: usize| {
+        let lexer = Lexer::new(source).unwrap();
+        let mut reader = PeekReader::new(lexer).unwrap();
+        let mut parser = Parser::new(&mut reader, SourceType::Script).unwrap();
+        parser.set_max_depth(max_depth);
+        Program::parse(&mut parser)
+            .err()
+            .map(|error| error.kind().clone())
+    };
+
+    let source = nested("(", "a", ")", 10);
+    assert_eq!(parse_error(&source, 20), None);
+    assert_eq!(parse_error(&source, 5), Some(ErrorKind::NestingTooDeep));
+}
@@ -0,0 +1,58 @@
+use fajt_parser::parse_program;
+
+/// Small xorshift generator, deterministic so failures can be reproduced.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, max: usize) -> usize {
+        (self.next() % max as u64) as usize
+    }
+}
+
+const FRAGMENTS: &[&str] = &[
+    "(", ")", "{", "}", "[", "]", ";", ",", ".", "...", "=", "=>", "+", "++", "-", "*", "**", "/",
+    "%", "!", "?", "?.", "??", ":", "<", ">", "&", "&&", "|", "^", "~", "'", "\"", "`", "${", "\\",
+    "\\u", "\\u{", " ", "\n", "\r", "\t", "\u{2028}", "\u{feff}", "#", "@", "a", "b", "$", "_",
+    "é", "0", "1", "0x", "0b", "0o", "08", "1e", "1n", ".5", "function", "async", "await", "yield",
+    "class", "extends", "super", "new", "this", "let", "const", "var", "if", "else", "for", "in",
+    "of", "while", "do", "break", "continue", "return", "switch", "case", "default", "try",
+    "catch", "finally", "throw", "import", "export", "from", "as", "with", "get", "set", "static",
+    "delete", "typeof", "void", "debugger", "target", "meta", "/*", "*/", "//", "<!--", "-->",
+];
+
+/// Random source from JavaScript like fragments mixed with arbitrary characters.
+fn random_source(random: &mut Random) -> String {
+    let length = random.below(24);
+    let mut source = String::new();
+    for _ in 0..length {
+        if random.below(8) == 0 {
+            let code_point = random.below(char::MAX as usize + 1) as u32;
+            source.extend(char::from_u32(code_point));
+        } else {
+            source.push_str(FRAGMENTS[random.below(FRAGMENTS.len())]);
+        }
+    }
+    source
+}
+
+#[test]
+fn random_input_never_panics() {
+    let iterations: usize = std::env::var("NO_PANIC_ITERATIONS")
+        .ok()
+        .and_then(|iterations| iterations.parse().ok())
+        .unwrap_or(5_000);
+
+    let mut random = Random(0x2545_f491_4f6c_dd1d);
+    for _ in 0..iterations {
+        let source = random_source(&mut random);
+        let result = std::panic::catch_unwind(|| parse_program(&source).is_ok());
+        assert!(result.is_ok(), "Parser panicked on input: {:?}", source);
+    }
+}
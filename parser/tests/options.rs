@@ -0,0 +1,117 @@
+use fajt_ast::{Atom, Expr, Program, SourceType, Span, Stmt};
+use fajt_parser::error::ErrorKind;
+use fajt_parser::{parse, parse_with_options, ParseOptions};
+
+#[test]
+fn yield_is_an_expression_with_yield_option() {
+    let options = ParseOptions::new(SourceType::Script).with_yield(true);
+    let expr = parse_with_options::<Expr>("yield x", &options).unwrap();
+    assert!(matches!(expr, Expr::Yield(_)));
+}
+
+#[test]
+fn yield_is_an_identifier_without_yield_option() {
+    let options = ParseOptions::new(SourceType::Script);
+    let expr = parse_with_options::<Expr>("yield x", &options).unwrap();
+    assert!(matches!(expr, Expr::IdentRef(_)));
+}
+
+#[test]
+fn await_option() {
+    let options = ParseOptions::new(SourceType::Script).with_await(true);
+    let expr = parse_with_options::<Expr>("await x", &options).unwrap();
+    assert!(matches!(expr, Expr::Await(_)));
+
+    let expr = parse::<Expr>("await x", SourceType::Script).unwrap();
+    assert!(matches!(expr, Expr::IdentRef(_)));
+}
+
+#[test]
+fn strict_option() {
+    let source = "var let = 1;";
+    assert!(parse::<Program>(source, SourceType::Script).is_ok());
+
+    let options = ParseOptions::new(SourceType::Script).with_strict(true);
+    assert!(parse_with_options::<Program>(source, &options).is_err());
+}
+
+#[test]
+fn return_option() {
+    assert!(parse::<Stmt>("return 1;", SourceType::Script).is_err());
+
+    let options = ParseOptions::new(SourceType::Script).with_return(true);
+    let stmt = parse_with_options::<Stmt>("return 1;", &options).unwrap();
+    assert!(matches!(stmt, Stmt::Return(_)));
+}
+
+#[test]
+fn options_apply_to_unknown_source_type() {
+    let options = ParseOptions::new(SourceType::Unknown).with_yield(true);
+    let program = parse_with_options::<Program>("import a from 'b'; yield a;", &options).unwrap();
+    assert!(program.is_module());
+}
+
+#[test]
+fn unknown_source_type_errors() {
+    let error = parse::<Program>("export {};", SourceType::Script).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::ModuleDeclarationInScript(_)
+    ));
+    assert_eq!(
+        error.message(),
+        "Cannot use `export` statement outside a module"
+    );
+
+    // The script fails on `import`, so the error of the module is the relevant one.
+    let source = "import a from 'b';\nwith (a) {}";
+    let error = parse::<Program>(source, SourceType::Unknown).unwrap_err();
+    assert_eq!(error.span(), &Span::new(19, 23));
+
+    // Not module syntax, the source is not parsed as a module.
+    let error = parse::<Program>("a b;", SourceType::Unknown).unwrap_err();
+    assert_eq!(error.span(), &Span::new(2, 3));
+
+    let program = parse::<Program>("await a;", SourceType::Unknown).unwrap();
+    assert!(program.is_module());
+}
+
+fn binary_operand_names(expr: Expr) -> (Atom, Atom) {
+    let Expr::Binary(binary) = expr else {
+        panic!("Expected binary expression");
+    };
+    let (Expr::IdentRef(left), Expr::IdentRef(right)) = (*binary.left, *binary.right) else {
+        panic!("Expected identifier operands");
+    };
+    (left.name, right.name)
+}
+
+#[test]
+fn interned_identifiers_share_allocation() {
+    let options = ParseOptions::new(SourceType::Script).with_interned_identifiers(true);
+    let expr = parse_with_options::<Expr>("name + name", &options).unwrap();
+
+    let (left, right) = binary_operand_names(expr);
+    assert_eq!(left, right);
+    assert_eq!(left, "name");
+    assert!(left.ptr_eq(&right));
+}
+
+#[test]
+fn identifiers_are_not_interned_by_default() {
+    let expr = parse::<Expr>("name + name", SourceType::Script).unwrap();
+
+    let (left, right) = binary_operand_names(expr);
+    assert_eq!(left, right);
+    assert!(!left.ptr_eq(&right));
+}
+
+#[test]
+fn interning_does_not_change_result() {
+    let source = "function f(a, b) { return a + b + f(a); } f(1, 2);";
+    let options = ParseOptions::new(SourceType::Script).with_interned_identifiers(true);
+
+    let interned = parse_with_options::<Program>(source, &options).unwrap();
+    let plain = parse::<Program>(source, SourceType::Script).unwrap();
+    assert_eq!(interned, plain);
+}
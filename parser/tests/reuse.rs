@@ -0,0 +1,67 @@
+use fajt_ast::{Program, SourceType};
+use fajt_common::io::PeekReader;
+use fajt_lexer::Lexer;
+use fajt_parser::{Parse, Parser};
+
+const SNIPPETS: &[&str] = &[
+    "var a = 1;",
+    "function f(a, b) { return a + b; }",
+    "'use strict'; let { a, ...b } = c;",
+    "class A extends B { constructor() { super(); } }",
+    "for (let i = 0; i < 10; i++) { continue; }",
+    "a = (b, c) => b ? c : d;",
+    "if (a) b(); else { c }",
+    "label: while (true) break label;",
+    "((((a))))",
+    "var = ;",
+    "let let = 1;",
+    "a => { yield 1 }",
+];
+
+fn snippets() -> impl Iterator<Item = String> {
+    (0..1000).map(|i| format!("{}\n{}", SNIPPETS[i % SNIPPETS.len()], "x;".repeat(i % 7)))
+}
+
+/// Result of parsing, errors are compared by message since they are not comparable.
+fn outcome(result: fajt_parser::error::Result<Program>) -> Result<Program, String> {
+    result.map_err(|error| error.to_string())
+}
+
+#[test]
+fn reused_parser_equals_fresh_parser() {
+    let snippets: Vec<String> = snippets().collect();
+
+    let mut reader = PeekReader::new(Lexer::new("").unwrap()).unwrap();
+    let mut parser = Parser::new(&mut reader, SourceType::Script).unwrap();
+
+    for snippet in &snippets {
+        let fresh = outcome(fajt_parser::parse::<Program>(snippet, SourceType::Script));
+
+        parser
+            .reset(Lexer::new(snippet).unwrap(), SourceType::Script)
+            .unwrap();
+        let reused = outcome(Program::parse(&mut parser));
+
+        assert_eq!(
+            reused, fresh,
+            "Reused parser result differs for `{}`",
+            snippet
+        );
+    }
+}
+
+#[test]
+fn reset_to_module() {
+    let mut reader = PeekReader::new(Lexer::new("").unwrap()).unwrap();
+    let mut parser = Parser::new(&mut reader, SourceType::Script).unwrap();
+
+    parser
+        .reset(Lexer::new("with (a) {}").unwrap(), SourceType::Module)
+        .unwrap();
+    assert!(Program::parse(&mut parser).is_err());
+
+    parser
+        .reset(Lexer::new("with (a) {}").unwrap(), SourceType::Script)
+        .unwrap();
+    assert!(Program::parse(&mut parser).is_ok());
+}
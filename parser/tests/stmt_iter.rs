@@ -0,0 +1,90 @@
+use fajt_ast::{Program, SourceType, Stmt};
+use fajt_common::io::PeekReader;
+use fajt_lexer::Lexer;
+use fajt_parser::error::ErrorKind;
+use fajt_parser::{parse_script, Parser};
+
+/// Parses `source` with `Parser::stmts`, returns the statements and the error kind if any.
+fn iterate(source: &str) -> (Vec<Stmt>, Option<ErrorKind>) {
+    let lexer = Lexer::new(source).unwrap();
+    let mut reader = PeekReader::new(lexer).unwrap();
+    let mut parser = Parser::new(&mut reader, SourceType::Script).unwrap();
+
+    let mut stmts = Vec::new();
+    for stmt in parser.stmts() {
+        match stmt {
+            Ok(stmt) => stmts.push(stmt),
+            Err(error) => return (stmts, Some(error.kind().clone())),
+        }
+    }
+
+    (stmts, None)
+}
+
+fn eager_body(source: &str) -> Vec<Stmt> {
+    match parse_script(source).unwrap() {
+        Program::Script(stmt_list) | Program::Module(stmt_list) => stmt_list.body,
+    }
+}
+
+#[test]
+fn matches_eagerly_parsed_body() {
+    let source = r#"
+        var a = 1;
+        function f(b) { return b * 2; }
+        label: for (;;) { break label; }
+        class C extends f {}
+        if (a) f(a); else { a++; }
+    "#;
+
+    let (stmts, error) = iterate(source);
+    assert_eq!(error, None);
+    assert_eq!(stmts, eager_body(source));
+}
+
+#[test]
+fn directives_are_not_iterated() {
+    let source = "'use strict'; a;";
+    let (stmts, error) = iterate(source);
+    assert_eq!(error, None);
+    assert_eq!(stmts, eager_body(source));
+    assert_eq!(stmts.len(), 1);
+}
+
+#[test]
+fn directives_apply_strict_mode() {
+    let (stmts, error) = iterate("'use strict'; a; with (a) {}");
+    assert_eq!(stmts.len(), 1);
+    assert!(error.is_some());
+}
+
+#[test]
+fn ends_after_error() {
+    let lexer = Lexer::new("a; ) b;").unwrap();
+    let mut reader = PeekReader::new(lexer).unwrap();
+    let mut parser = Parser::new(&mut reader, SourceType::Script).unwrap();
+
+    let mut stmts = parser.stmts();
+    assert!(matches!(stmts.next(), Some(Ok(_))));
+    assert!(matches!(stmts.next(), Some(Err(_))));
+    assert!(stmts.next().is_none());
+}
+
+#[test]
+fn empty_source() {
+    let (stmts, error) = iterate("");
+    assert!(stmts.is_empty());
+    assert_eq!(error, None);
+}
+
+#[test]
+fn directives_parsed_with_first_statement() {
+    let lexer = Lexer::new("'use strict'; a;").unwrap();
+    let mut reader = PeekReader::new(lexer).unwrap();
+    let mut parser = Parser::new(&mut reader, SourceType::Script).unwrap();
+
+    let mut stmts = parser.stmts();
+    assert!(stmts.directives().is_none());
+    assert!(matches!(stmts.next(), Some(Ok(_))));
+    assert!(stmts.directives().unwrap()[0].is_use_strict());
+}
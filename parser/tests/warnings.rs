@@ -0,0 +1,58 @@
+use fajt_ast::{Program, SourceType, Span};
+use fajt_parser::warning::{Warning, WarningKind};
+use fajt_parser::{parse_with_warnings, ParseOptions};
+
+fn warnings(source: &str, source_type: SourceType) -> Vec<Warning> {
+    let options = ParseOptions::new(source_type);
+    let (_, warnings) = parse_with_warnings::<Program>(source, &options).unwrap();
+    warnings
+}
+
+#[test]
+fn with_statement_in_sloppy_mode() {
+    let warnings = warnings("with (a) { b; }", SourceType::Script);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind(), &WarningKind::WithStatement);
+    assert_eq!(warnings[0].span(), &Span::new(0, 4));
+    assert_eq!(warnings[0].message(), "Use of `with` statement");
+}
+
+#[test]
+fn legacy_octal_in_sloppy_mode() {
+    let warnings = warnings("a = 010; b = '\\01';", SourceType::Script);
+    let kinds: Vec<_> = warnings.iter().map(Warning::kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            &WarningKind::LegacyOctalLiteral,
+            &WarningKind::LegacyOctalEscape
+        ]
+    );
+    assert_eq!(warnings[0].span(), &Span::new(4, 7));
+}
+
+#[test]
+fn no_warnings() {
+    assert!(warnings("a = 10; with_ = 1;", SourceType::Script).is_empty());
+    assert!(warnings("import a from 'b';", SourceType::Unknown).is_empty());
+}
+
+#[test]
+fn warning_in_arrow_function_body_is_not_duplicated() {
+    let warnings = warnings("(a) => { with (a) {} }", SourceType::Script);
+    assert_eq!(warnings.len(), 1);
+}
+
+#[test]
+fn many_warnings() {
+    let source = "(a) => [010, 010];".repeat(10_000);
+    let warnings = warnings(&source, SourceType::Script);
+    assert_eq!(warnings.len(), 20_000);
+    assert_eq!(warnings[19_999].span(), &Span::new(179_995, 179_998));
+}
+
+#[test]
+fn strict_mode_is_still_an_error() {
+    let options = ParseOptions::new(SourceType::Module);
+    assert!(parse_with_warnings::<Program>("with (a) {}", &options).is_err());
+}
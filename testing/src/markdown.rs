@@ -1,5 +1,7 @@
 mod section;
 
+use std::fmt;
+
 pub use section::*;
 
 /// Representation of a simple markdown document.
@@ -16,11 +18,11 @@ impl<'a> Markdown<'a> {
         Markdown { sections }
     }
 
-    pub fn get_section(&self, name: &str) -> Option<&MarkdownSection> {
+    pub fn get_section(&self, name: &str) -> Option<&MarkdownSection<'_>> {
         self.sections.iter().find(|s| s.name == name)
     }
 
-    pub fn get_block(&self, section_name: &str) -> Option<&MarkdownBlock> {
+    pub fn get_block(&self, section_name: &str) -> Option<&MarkdownBlock<'_>> {
         self.get_section(section_name)
             .and_then(|section| section.block.as_ref())
     }
@@ -36,10 +38,10 @@ impl<'a> Markdown<'a> {
     }
 }
 
-impl ToString for Markdown<'_> {
-    fn to_string(&self) -> String {
+impl fmt::Display for Markdown<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let sections: Vec<String> = self.sections.iter().map(|s| s.to_string()).collect();
-        sections.join("\n\n")
+        f.write_str(&sections.join("\n\n"))
     }
 }
 
@@ -1,4 +1,5 @@
 use regex::Regex;
+use std::fmt::{self, Write};
 
 #[derive(Eq, PartialEq, Debug)]
 pub struct MarkdownSection<'a> {
@@ -14,7 +15,7 @@ pub struct MarkdownBlock<'a> {
 }
 
 impl MarkdownSection<'_> {
-    pub fn from_string(data: &str) -> Vec<MarkdownSection> {
+    pub fn from_string(data: &str) -> Vec<MarkdownSection<'_>> {
         let reg = Regex::new(r"(?m)^###").unwrap();
         reg.split(data)
             .filter(|s| !s.is_empty())
@@ -32,13 +33,12 @@ impl MarkdownSection<'_> {
     }
 }
 
-impl ToString for MarkdownBlock<'_> {
-    fn to_string(&self) -> String {
-        let mut string = String::new();
-        string.push_str("```");
-        string.push_str(self.language);
-        string.push('\n');
-        string.push_str(self.contents);
+impl fmt::Display for MarkdownBlock<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("```")?;
+        f.write_str(self.language)?;
+        f.write_char('\n')?;
+        f.write_str(self.contents)?;
 
         if self
             .contents
@@ -47,30 +47,28 @@ impl ToString for MarkdownBlock<'_> {
             .map(|last| last != '\n')
             .unwrap_or(true)
         {
-            string.push('\n');
+            f.write_char('\n')?;
         }
-        string.push_str("```");
-        string
+        f.write_str("```")
     }
 }
 
-impl ToString for MarkdownSection<'_> {
-    fn to_string(&self) -> String {
-        let mut string = String::new();
-        string.push_str("### ");
-        string.push_str(self.name);
-        string.push('\n');
+impl fmt::Display for MarkdownSection<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("### ")?;
+        f.write_str(self.name)?;
+        f.write_char('\n')?;
 
         if let Some(text) = self.text {
-            string.push_str(text);
-            string.push_str("\n\n");
+            f.write_str(text)?;
+            f.write_str("\n\n")?;
         }
 
         if let Some(block) = self.block.as_ref() {
-            string.push_str(&block.to_string());
+            block.fmt(f)?;
         }
 
-        string
+        Ok(())
     }
 }
 
@@ -80,7 +78,7 @@ fn split_title(str: &str) -> (&str, &str) {
     (title_line.trim(), rest.trim())
 }
 
-fn split_code_block(str: &str) -> (Option<&str>, Option<MarkdownBlock>) {
+fn split_code_block(str: &str) -> (Option<&str>, Option<MarkdownBlock<'_>>) {
     let reg = Regex::new(r"(?m)^```").unwrap();
     if let Some(m) = reg.find(str) {
         let start = m.end();
@@ -18,6 +18,9 @@
 //!     }
 //!     ```
 //!
+//! The stored ast is also deserialized and serialized again, the result must be identical to the
+//! stored json.
+//!
 //! The "Output" sections are optional. If you add the title of a section and run the test the
 //! actual output will be generated.
 //!
@@ -46,6 +49,9 @@
 //! - `source:module`
 //! - `source:unknown`
 //!
+//! Syntax behind a parser feature is only tested when the feature is enabled, i.e. `cargo test
+//! --features do-expressions` for `feature:do-expressions`.
+//!
 #![allow(dead_code)]
 
 extern crate core;
@@ -78,6 +84,12 @@ fn run_test(path: &str) {
     let test = Markdown::from_string(&data);
 
     if let Some(source_block) = test.get_block(SOURCE_SECTION) {
+        let feature = get_attribute(source_block.language, "feature:");
+        if feature.is_some_and(|feature| !is_feature_enabled(feature)) {
+            println!("Skipped, feature is not enabled.");
+            return;
+        }
+
         let parse_type = get_attribute(source_block.language, "parse:").unwrap_or("program");
         let source_type = get_source_type(source_block.language);
         match parse_type {
@@ -103,6 +115,7 @@ where
 
     if let Some(ast_section) = test.get_section(AST_SECTION) {
         if let Some(ast) = ast_section.get_code() {
+            assert_json_round_trip::<T>(ast);
             assert_ast(&result, ast);
         } else {
             regenerate_ast = true;
@@ -174,6 +187,21 @@ where
     }
 }
 
+/// Deserializing the stored AST and serializing it again must produce the same JSON, otherwise
+/// the serde representation has drifted from what the snapshots contain.
+fn assert_json_round_trip<T>(ast_json: &str)
+where
+    T: Serialize + DeserializeOwned,
+{
+    let ast: T = serde_json::from_str(ast_json).unwrap();
+    let reserialized = serde_json::to_string_pretty(&ast).unwrap();
+    assert_eq!(
+        reserialized.trim(),
+        ast_json.trim(),
+        "AST JSON is not stable when deserialized and serialized again."
+    );
+}
+
 fn assert_source_format<T>(source_block: &MarkdownBlock, source: &str, result: &mut Result<T>)
 where
     T: Parse + Serialize + DeserializeOwned + PartialEq + Debug + Traverse,
@@ -218,13 +246,22 @@ where
 }
 
 fn get_source_type(language: &str) -> SourceType {
-    let source_type = get_attribute(language, "source:").unwrap_or("unknown");
+    let source_type = get_attribute(language, "source:").unwrap_or("script");
     match source_type {
         "module" => SourceType::Module,
+        "unknown" => SourceType::Unknown,
         _ => SourceType::Script,
     }
 }
 
+fn is_feature_enabled(feature: &str) -> bool {
+    match feature {
+        "do-expressions" => cfg!(feature = "do-expressions"),
+        "decorators" => cfg!(feature = "decorators"),
+        _ => panic!("Unknown feature `{feature}`"),
+    }
+}
+
 fn get_attribute<'a>(language: &'a str, attribute: &str) -> Option<&'a str> {
     language
         .split(' ')
@@ -286,6 +323,7 @@ generate_test_modules!(
     strict_mode: ["tests/cases/strict-mode"],
     source_module: ["tests/cases/source-module"],
     source_script: ["tests/cases/source-script"],
+    source_unknown: ["tests/cases/source-unknown"],
     comment: ["tests/cases/comment"],
     error: ["tests/cases/error"],
 );